@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::ai::characteristics::home::Home;
+
+use super::components::Destination;
+
+#[derive(Component, Clone, Debug)]
+pub struct BedDestination;
+
+pub fn bed_destination(
+    mut commands: Commands,
+    homes: Query<&Home>,
+    mut actions: Query<(&Actor, &mut ActionState, &BedDestination)>,
+) {
+    for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                if let Ok(home) = homes.get(*actor) {
+                    trace!("Setting bed destination.");
+                    commands.entity(*actor).insert(Destination::new(home.position, false));
+                    *action_state = ActionState::Success;
+                } else {
+                    //Lost our home on the way.
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::{
+        social::{Loneliness, Relationships},
+        MoodEvent, MoodEventOccurred,
+    },
+    map::TILE_SIZE,
+};
+
+use super::components::ChatPartner;
+
+// How far apart, in tiles, two villagers can be and still talk.
+const CHAT_DISTANCE: f32 = 2.0;
+// Relationship gained for every point of loneliness taken away.
+const RELATIONSHIP_RATE: f32 = 0.25;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Chat {
+    //Loneliness taken away every second
+    pub per_second: f32,
+}
+
+pub fn chat(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut lonelinesses: Query<&mut Loneliness>,
+    mut relationships: Query<&mut Relationships>,
+    positions: Query<&Transform>,
+    partners: Query<&ChatPartner>,
+    mut actions: Query<(&Actor, &mut ActionState, &Chat)>,
+    mut mood_events: EventWriter<MoodEventOccurred>,
+) {
+    for (Actor(actor), mut action_state, chat) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let partner = match partners.get(*actor) {
+                    Ok(ChatPartner(partner)) => *partner,
+                    Err(_) => {
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                };
+                let close_enough = match (positions.get(*actor), positions.get(partner)) {
+                    (Ok(actor_transform), Ok(partner_transform)) => {
+                        actor_transform
+                            .translation
+                            .xy()
+                            .distance(partner_transform.translation.xy())
+                            <= CHAT_DISTANCE * TILE_SIZE.x
+                    }
+                    _ => false,
+                };
+                if !close_enough {
+                    //They wandered off, or are gone altogether.
+                    commands.entity(*actor).remove::<ChatPartner>();
+                    *action_state = ActionState::Failure;
+                    continue;
+                }
+
+                let amount = chat.per_second * time.delta_seconds();
+                if let Ok([mut actor_relationships, mut partner_relationships]) =
+                    relationships.get_many_mut([*actor, partner])
+                {
+                    actor_relationships.improve(partner, amount * RELATIONSHIP_RATE);
+                    partner_relationships.improve(*actor, amount * RELATIONSHIP_RATE);
+                }
+
+                let mut actor_loneliness = lonelinesses.get_mut(*actor).expect("Actor has no loneliness.");
+                actor_loneliness.loneliness = f32::max(actor_loneliness.loneliness - amount, 0.0);
+                if actor_loneliness.loneliness <= 0.0 {
+                    commands.entity(*actor).remove::<ChatPartner>();
+                    mood_events.send(MoodEventOccurred {
+                        villager: *actor,
+                        event: MoodEvent::Chatted,
+                    });
+                    *action_state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                commands.entity(*actor).remove::<ChatPartner>();
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
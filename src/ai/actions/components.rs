@@ -29,3 +29,15 @@ impl HasJob {
         Self { job, progress: 0.0 }
     }
 }
+
+/// The villager an actor is on their way to chat with.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChatPartner(pub Entity);
+
+/// What an action is called when showing villagers' behaviour to the player.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionLabel(pub &'static str);
+
+/// Marks the action a thinker picked, with the name of the scorer that won.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PickedOption(pub &'static str);
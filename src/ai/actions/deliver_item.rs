@@ -0,0 +1,88 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    items::{Container, Inventory, ItemLocation, ItemQuery, Storage, TownInventory},
+    jobs::job_queue::JobQueue,
+    map::world_xy_tile_xy,
+    zones::{Stockpile, Zone},
+};
+
+use super::components::HasJob;
+
+#[derive(Component, Clone, Debug)]
+pub struct DeliverItem;
+
+pub fn deliver_item(
+    mut commands: Commands,
+    mut job_queue: ResMut<JobQueue>,
+    mut town_inventory: ResMut<TownInventory>,
+    mut item_query: ItemQuery,
+    time: Res<Time>,
+    mut actors: Query<(&Transform, &mut HasJob, &mut Inventory)>,
+    mut storages: Query<(&Zone, &mut Container, Option<&Stockpile>), With<Storage>>,
+    mut actions: Query<(&Actor, &mut ActionState, &DeliverItem)>,
+) {
+    for (Actor(actor), mut action_state, _deliver) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, mut actor_job, mut actor_inventory) = actors
+                    .get_mut(*actor)
+                    .expect("Actor should have a position, job and inventory.");
+                let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
+                let item = actor_inventory.stacks().first().map(|stack| stack.item);
+                let storage = storages.iter_mut().find(|(zone, container, stockpile)| {
+                    zone.is_neighbor(&actor_tile)
+                        && item.map_or(true, |item| super::shared_hauling::accepts(container, *stockpile, item))
+                });
+                let mut storage = match storage {
+                    Some((_, storage, _)) => storage,
+                    None => {
+                        //We're too far away, or the storage filled up on our way here.
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                };
+
+                actor_job.progress += actor_job.job.job_type.speed() * time.delta_seconds();
+                if actor_job.progress < 100.0 {
+                    continue;
+                }
+
+                let mut delivered = true;
+                for mut stack in actor_inventory.drain() {
+                    let carried = stack.count;
+                    storage.insert(&mut stack);
+                    town_inventory.transfer(
+                        stack.item,
+                        carried - stack.count,
+                        ItemLocation::InTransit,
+                        ItemLocation::Stored,
+                    );
+                    if !stack.is_empty() {
+                        //Put down what doesn't fit, so it gets hauled again from here.
+                        warn!("Storage is full, dropping {} {}.", stack.count, stack.item.name());
+                        town_inventory.remove(stack.item, ItemLocation::InTransit, stack.count);
+                        item_query.drop_item(stack, actor_tile);
+                        delivered = false;
+                    }
+                }
+
+                job_queue.complete(actor_job.job.id);
+                commands.entity(*actor).remove::<HasJob>();
+                *action_state = if delivered {
+                    ActionState::Success
+                } else {
+                    ActionState::Failure
+                };
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
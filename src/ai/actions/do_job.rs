@@ -2,26 +2,53 @@ use bevy::{math::Vec3Swizzles, prelude::*};
 use bevy_ecs_tilemap::prelude::*;
 use big_brain::prelude::*;
 
-use crate::map::{
-    components::{Choppable, Growing, Mineable},
-    is_neighbor, world_xy_tile_xy, FeatureQuery, Features, Map, MapPathfinding,
+use crate::{
+    ai::{
+        characteristics::{Energy, Health, Mood, MoodEvent, MoodEventOccurred, Skills},
+        wildlife::Animal,
+    },
+    items::{Container, Inventory, ItemLocation, ItemQuery, Storage, TownInventory},
+    jobs::{job_queue::JobQueue, Jobs},
+    map::{
+        components::{Choppable, Growing, Harvestable, Mineable},
+        is_neighbor, world_xy_tile_xy, FeatureQuery, Features, Map, MapPathfinding,
+    },
 };
 
 use super::components::HasJob;
 
+const JOB_EXPERIENCE: f32 = 10.0;
+// How many times faster crops grow than trees on the most fertile land.
+const CROP_GROWTH_MULTIPLIER: f32 = 4.0;
+
 #[derive(Component, Clone, Copy, Debug)]
 pub struct DoJob;
 
 pub fn do_job(
     mut commands: Commands,
     mut feature_query: FeatureQuery,
+    mut item_query: ItemQuery,
     mut map: ResMut<Map>,
     mut map_pathfinding: ResMut<MapPathfinding>,
+    mut job_queue: ResMut<JobQueue>,
+    mut town_inventory: ResMut<TownInventory>,
     time: Res<Time>,
-    mut actors: Query<(&Transform, &mut HasJob)>,
+    mut actors: Query<(
+        &Transform,
+        &mut HasJob,
+        &mut Skills,
+        &mut Inventory,
+        &Energy,
+        &Mood,
+        &Health,
+    )>,
     chop_query: Query<Entity, With<Choppable>>,
     mine_query: Query<Entity, With<Mineable>>,
+    harvest_query: Query<Entity, With<Harvestable>>,
+    prey_query: Query<(&Transform, &Animal)>,
+    mut storages: Query<&mut Container, With<Storage>>,
     mut actions: Query<(&Actor, &mut ActionState, &DoJob)>,
+    mut mood_events: EventWriter<MoodEventOccurred>,
 ) {
     for (Actor(actor), mut action_state, _do_job) in actions.iter_mut() {
         match *action_state {
@@ -29,11 +56,48 @@ pub fn do_job(
                 *action_state = ActionState::Executing;
             }
             ActionState::Executing => {
-                let (actor_transform, mut actor_job) =
-                    actors.get_mut(*actor).expect("Actor should have a position and job.");
+                let (
+                    actor_transform,
+                    mut actor_job,
+                    mut actor_skills,
+                    mut actor_inventory,
+                    actor_energy,
+                    actor_mood,
+                    actor_health,
+                ) = actors
+                    .get_mut(*actor)
+                    .expect("Actor should have a position, job, skills, inventory, energy, mood and health.");
+                if super::shared_hauling::materials_to_fetch(&actor_job.job, &actor_inventory).is_some() {
+                    warn!("Actor is missing the materials for their job.");
+                    //Put the job back so materials get set aside for it again.
+                    super::shared_hauling::release_materials(
+                        &actor_job.job,
+                        Some(&actor_inventory),
+                        &job_queue,
+                        &mut storages,
+                        &mut town_inventory,
+                    );
+                    job_queue.requeue(actor_job.job);
+                    commands.entity(*actor).remove::<HasJob>();
+                    *action_state = ActionState::Failure;
+                    continue;
+                }
+
                 let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
-                if is_neighbor(&actor_tile, &actor_job.job.position) {
-                    actor_job.progress += actor_job.job.job_type.speed() * time.delta_seconds();
+                let in_reach = match actor_job.job.job_type {
+                    //Prey keeps moving, so it's wherever it is now that counts.
+                    Jobs::Hunt(prey) => prey_query.get(prey).map_or(false, |(prey_transform, _)| {
+                        is_neighbor(&actor_tile, &world_xy_tile_xy(prey_transform.translation.xy()))
+                    }),
+                    _ => is_neighbor(&actor_tile, &actor_job.job.position),
+                };
+                if in_reach {
+                    let skill = actor_job.job.job_type.skill();
+                    let multiplier = skill.map_or(1.0, |skill| actor_skills.work_multiplier(skill))
+                        * actor_energy.work_multiplier()
+                        * actor_mood.work_multiplier()
+                        * actor_health.work_multiplier();
+                    actor_job.progress += actor_job.job.job_type.speed() * multiplier * time.delta_seconds();
 
                     if actor_job.progress >= 100.0 {
                         match actor_job.job.job_type {
@@ -48,6 +112,7 @@ pub fn do_job(
                                             chop_target,
                                             &mut commands,
                                             &mut feature_query,
+                                            &mut item_query,
                                         );
                                     }
                                 }
@@ -62,11 +127,40 @@ pub fn do_job(
                                             &mut map,
                                             &mut map_pathfinding,
                                             &mut feature_query,
+                                            &mut item_query,
                                         );
                                     }
                                 }
                             }
+                            crate::jobs::Jobs::Harvest => {
+                                let feature = feature_query.get_feature(&actor_job.job.position);
+                                if let Some(feature_layer) = feature {
+                                    if let Ok(harvest_target) = harvest_query.get(feature_layer) {
+                                        do_harvest(
+                                            &actor_job.job.position,
+                                            &mut map,
+                                            harvest_target,
+                                            &mut commands,
+                                            &mut feature_query,
+                                            &mut item_query,
+                                            &mut job_queue,
+                                        );
+                                    }
+                                }
+                            }
+                            crate::jobs::Jobs::Deconstruct => do_deconstruct(
+                                &actor_job.job.position,
+                                &mut map,
+                                &mut map_pathfinding,
+                                &mut feature_query,
+                                &mut item_query,
+                            ),
                             crate::jobs::Jobs::Build(feature) => {
+                                if let Some(materials) = actor_job.job.materials {
+                                    if let Some(used) = actor_inventory.take(materials.item, materials.count) {
+                                        town_inventory.remove(used.item, ItemLocation::InTransit, used.count);
+                                    }
+                                }
                                 do_build(
                                     &actor_job.job.position,
                                     feature,
@@ -80,13 +174,51 @@ pub fn do_job(
                                 &mut map,
                                 &mut map_pathfinding,
                                 &mut feature_query,
+                                &mut item_query,
                             ),
+                            crate::jobs::Jobs::Haul(_) => {
+                                error!("Haul jobs should be done by hauling, not by DoJob.");
+                            }
+                            crate::jobs::Jobs::Till => {
+                                do_build(
+                                    &actor_job.job.position,
+                                    Features::Farmland,
+                                    &mut map,
+                                    &mut map_pathfinding,
+                                    &mut feature_query,
+                                );
+                                job_queue.push(Jobs::Sow, actor_job.job.position);
+                            }
+                            crate::jobs::Jobs::Sow => {
+                                if let Some(sow_target) = feature_query.get_feature(&actor_job.job.position) {
+                                    do_sow(
+                                        &actor_job.job.position,
+                                        &mut map,
+                                        sow_target,
+                                        &mut commands,
+                                        &mut feature_query,
+                                    );
+                                }
+                            }
+                            crate::jobs::Jobs::Hunt(prey) => {
+                                if let Ok((prey_transform, animal)) = prey_query.get(prey) {
+                                    do_hunt(prey, prey_transform, animal, &mut commands, &mut item_query);
+                                }
+                            }
+                        }
+                        if let Some(skill) = skill {
+                            actor_skills.gain_experience(skill, JOB_EXPERIENCE);
                         }
+                        job_queue.complete(actor_job.job.id);
                         commands.entity(*actor).remove::<HasJob>();
+                        mood_events.send(MoodEventOccurred {
+                            villager: *actor,
+                            event: MoodEvent::FinishedJob,
+                        });
                         *action_state = ActionState::Success;
                     }
                 } else {
-                    //We're too far away.
+                    //We're too far away, or our prey moved off and has to be caught up with.
                     *action_state = ActionState::Failure;
                 }
             }
@@ -104,10 +236,15 @@ fn do_chop(
     chop_target: Entity,
     commands: &mut Commands,
     feature_query: &mut FeatureQuery,
+    item_query: &mut ItemQuery,
 ) {
     let idx = map.tile_xy_idx(chop_target_pos.x, chop_target_pos.y);
 
     let current_feature = map.features[idx];
+    if let Some(drops) = current_feature.and_then(|feature| feature.drops()) {
+        item_query.drop_item(drops, *chop_target_pos);
+    }
+
     let next_feature = match current_feature {
         Some(Features::Tree) => Some(Features::TreeStump),
         Some(Features::CoconutTree) => Some(Features::CoconutTreeStump),
@@ -127,13 +264,79 @@ fn do_chop(
     }
 }
 
+fn do_harvest(
+    harvest_target_pos: &TilePos,
+    map: &mut Map,
+    harvest_target: Entity,
+    commands: &mut Commands,
+    feature_query: &mut FeatureQuery,
+    item_query: &mut ItemQuery,
+    job_queue: &mut JobQueue,
+) {
+    let idx = map.tile_xy_idx(harvest_target_pos.x, harvest_target_pos.y);
+
+    if let Some(current_feature) = map.features[idx] {
+        if let Some(next_feature) = current_feature.harvested() {
+            if let Some(harvest) = current_feature.harvest_yield() {
+                item_query.drop_item(harvest, *harvest_target_pos);
+            }
+
+            map.features[idx] = Some(next_feature);
+            feature_query.change_feature_tile(harvest_target, next_feature);
+            commands.entity(harvest_target).remove::<Harvestable>();
+            if next_feature.grown().is_some() {
+                commands.entity(harvest_target).insert(Growing::new());
+            } else if next_feature == Features::Farmland {
+                //Harvested crops leave tilled soil behind, ready for the next sowing.
+                job_queue.push(Jobs::Sow, *harvest_target_pos);
+            }
+        }
+    }
+}
+
+fn do_hunt(
+    prey: Entity,
+    prey_transform: &Transform,
+    animal: &Animal,
+    commands: &mut Commands,
+    item_query: &mut ItemQuery,
+) {
+    item_query.drop_item(animal.species.meat(), world_xy_tile_xy(prey_transform.translation.xy()));
+    commands.entity(prey).despawn_recursive();
+}
+
+fn do_sow(
+    sow_target_pos: &TilePos,
+    map: &mut Map,
+    sow_target: Entity,
+    commands: &mut Commands,
+    feature_query: &mut FeatureQuery,
+) {
+    let idx = map.tile_xy_idx(sow_target_pos.x, sow_target_pos.y);
+    if map.features[idx] != Some(Features::Farmland) {
+        return;
+    }
+
+    map.features[idx] = Some(Features::CropSeedling);
+    feature_query.change_feature_tile(sow_target, Features::CropSeedling);
+    let fertility = map.tiles[idx].fertility();
+    commands
+        .entity(sow_target)
+        .insert(Growing::with_multiplier(CROP_GROWTH_MULTIPLIER * fertility));
+}
+
 fn do_mine(
     mine_target_pos: &TilePos,
     map: &mut Map,
     map_pathfinding: &mut MapPathfinding,
     feature_query: &mut FeatureQuery,
+    item_query: &mut ItemQuery,
 ) {
     let idx = map.tile_xy_idx(mine_target_pos.x, mine_target_pos.y);
+    if let Some(drops) = map.features[idx].and_then(|feature| feature.drops()) {
+        item_query.drop_item(drops, *mine_target_pos);
+    }
+
     map.features[idx] = None;
     map_pathfinding.announce_tile_changed(map, mine_target_pos);
     feature_query.despawn_feature(*mine_target_pos);
@@ -152,13 +355,44 @@ fn do_build(
     feature_query.spawn_feature(*build_pos, feature);
 }
 
+fn do_deconstruct(
+    deconstruct_pos: &TilePos,
+    map: &mut Map,
+    map_pathfinding: &mut MapPathfinding,
+    feature_query: &mut FeatureQuery,
+    item_query: &mut ItemQuery,
+) {
+    let idx = map.tile_xy_idx(deconstruct_pos.x, deconstruct_pos.y);
+    let feature = match map.features[idx] {
+        Some(feature) if feature.is_player_built() => feature,
+        _ => return,
+    };
+
+    if let Some(refund) = feature.deconstruct_refund() {
+        item_query.drop_item(refund, *deconstruct_pos);
+    }
+
+    // The map has to be updated before the path cache is told, since it reads the new costs from it.
+    map.features[idx] = None;
+    map_pathfinding.announce_tile_changed(map, deconstruct_pos);
+    // Also notifies the auto-tiler, so neighbouring walls lose their connection to this tile.
+    feature_query.despawn_feature(*deconstruct_pos);
+}
+
 fn do_clear(
     build_pos: &TilePos,
     map: &mut Map,
     map_pathfinding: &mut MapPathfinding,
     feature_query: &mut FeatureQuery,
+    item_query: &mut ItemQuery,
 ) {
     let idx = map.tile_xy_idx(build_pos.x, build_pos.y);
+    if let Some(feature) = map.features[idx] {
+        for drops in [feature.drops(), feature.harvest_yield()].into_iter().flatten() {
+            item_query.drop_item(drops, *build_pos);
+        }
+    }
+
     map.features[idx] = None;
     map_pathfinding.announce_tile_changed(map, build_pos);
     feature_query.despawn_feature(*build_pos);
@@ -4,7 +4,10 @@ use big_brain::prelude::*;
 
 use crate::{
     ai::characteristics::thirst::Thirst,
-    map::{components::WaterSource, is_neighbor, world_xy_tile_xy},
+    map::{
+        components::{WaterSource, WaterSourceCapacity},
+        is_neighbor, world_xy_tile_xy,
+    },
 };
 
 #[derive(Component, Clone, Copy, Debug)]
@@ -15,7 +18,8 @@ pub struct Drink {
 pub fn drink(
     time: Res<Time>,
     mut thirsts: Query<(&Transform, &mut Thirst), Without<WaterSource>>,
-    water_sources: Query<&TilePos, With<WaterSource>>,
+    water_sources: Query<(Entity, &TilePos), With<WaterSource>>,
+    mut capacities: Query<&mut WaterSourceCapacity>,
     mut actions: Query<(&Actor, &mut ActionState, &Drink)>,
 ) {
     for (Actor(actor), mut action_state, drink) in actions.iter_mut() {
@@ -26,27 +30,49 @@ pub fn drink(
             ActionState::Executing => {
                 let (actor_transform, mut actor_thirst) =
                     thirsts.get_mut(*actor).expect("Actor has no position and thirst.");
-                let water_source_tile =
-                    super::shared_drinking::find_closest_water_source(&water_sources, actor_transform);
+                let water_source = super::shared_drinking::find_closest_water_source(
+                    &water_sources,
+                    |water| {
+                        capacities
+                            .get(water)
+                            .map_or(true, |capacity| capacity.has_room_for(*actor))
+                    },
+                    actor_transform,
+                );
                 let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
-                if is_neighbor(&actor_tile, &water_source_tile) {
-                    actor_thirst.drink_progress += drink.per_second * time.delta_seconds();
+                match water_source {
+                    Some((water, water_tile)) if is_neighbor(&actor_tile, &water_tile) => {
+                        if let Ok(mut capacity) = capacities.get_mut(water) {
+                            capacity.claim(*actor);
+                        }
+                        actor_thirst.drink_progress += drink.per_second * time.delta_seconds();
 
-                    if actor_thirst.drink_progress > actor_thirst.thirst {
-                        actor_thirst.thirst = 0.0;
-                        actor_thirst.drink_progress = 0.0;
+                        if actor_thirst.drink_progress > actor_thirst.thirst {
+                            actor_thirst.thirst = 0.0;
+                            actor_thirst.drink_progress = 0.0;
 
-                        *action_state = ActionState::Success;
+                            release_water_sources(&mut capacities, *actor);
+                            *action_state = ActionState::Success;
+                        }
+                    }
+                    _ => {
+                        //We're too far away, or someone took our spot.
+                        release_water_sources(&mut capacities, *actor);
+                        *action_state = ActionState::Failure;
                     }
-                } else {
-                    //We're too far away.
-                    *action_state = ActionState::Failure;
                 }
             }
             ActionState::Cancelled => {
+                release_water_sources(&mut capacities, *actor);
                 *action_state = ActionState::Failure;
             }
             _ => {}
         }
     }
 }
+
+fn release_water_sources(capacities: &mut Query<&mut WaterSourceCapacity>, drinker: Entity) {
+    for mut capacity in capacities.iter_mut() {
+        capacity.release(drinker);
+    }
+}
@@ -0,0 +1,121 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use bevy_ecs_tilemap::tiles::TilePos;
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::{hunger::Hunger, MoodEvent, MoodEventOccurred},
+    items::{Container, ItemLocation, Storage, TownInventory},
+    map::{
+        components::{Growing, Harvestable},
+        is_neighbor, world_xy_tile_xy, FeatureQuery, Map,
+    },
+    zones::Zone,
+};
+
+use super::shared_eating::{FoodPlantQuery, FoodSource};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Eat {
+    pub per_second: f32,
+}
+
+pub fn eat(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut map: ResMut<Map>,
+    mut town_inventory: ResMut<TownInventory>,
+    mut feature_query: FeatureQuery,
+    mut hungers: Query<(&Transform, &mut Hunger)>,
+    mut storages: Query<(Entity, &Zone, &mut Container), With<Storage>>,
+    plants: FoodPlantQuery,
+    mut actions: Query<(&Actor, &mut ActionState, &Eat)>,
+    mut mood_events: EventWriter<MoodEventOccurred>,
+) {
+    for (Actor(actor), mut action_state, eat) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, mut actor_hunger) =
+                    hungers.get_mut(*actor).expect("Actor has no position and hunger.");
+                let food = super::shared_eating::find_closest_food(storages.iter(), &plants, &map, actor_transform);
+                let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
+                match food {
+                    Some((food_source, food_tile)) if is_neighbor(&actor_tile, &food_tile) => {
+                        actor_hunger.eat_progress += eat.per_second * time.delta_seconds();
+
+                        if actor_hunger.eat_progress > actor_hunger.hunger {
+                            let eaten = match food_source {
+                                FoodSource::Storage(storage) => {
+                                    let (_, _, mut container) =
+                                        storages.get_mut(storage).expect("Food storage should exist.");
+                                    eat_stored(&mut container, &mut town_inventory, actor_hunger.hunger)
+                                }
+                                FoodSource::Plant(plant) => {
+                                    eat_plant(plant, &food_tile, &mut map, &mut commands, &mut feature_query)
+                                }
+                            };
+                            actor_hunger.hunger = f32::max(actor_hunger.hunger - eaten, 0.0);
+                            actor_hunger.eat_progress = 0.0;
+                            mood_events.send(MoodEventOccurred {
+                                villager: *actor,
+                                event: MoodEvent::Ate,
+                            });
+
+                            *action_state = ActionState::Success;
+                        }
+                    }
+                    _ => {
+                        //We're too far away, or someone ate our food.
+                        *action_state = ActionState::Failure;
+                    }
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Eats just enough of the most filling stored food, returning how much hunger it took away.
+fn eat_stored(container: &mut Container, town_inventory: &mut TownInventory, hunger: f32) -> f32 {
+    let item = match super::shared_eating::best_food(container) {
+        Some(item) => item,
+        None => return 0.0,
+    };
+
+    let wanted = f32::max((hunger / item.nutrition()).ceil(), 1.0) as u32;
+    if let Some(eaten) = container.take(item, wanted) {
+        town_inventory.remove(item, ItemLocation::Stored, eaten.count);
+        eaten.count as f32 * item.nutrition()
+    } else {
+        0.0
+    }
+}
+
+/// Eats all the fruit on a plant, returning how much hunger it took away.
+fn eat_plant(
+    plant: Entity,
+    plant_pos: &TilePos,
+    map: &mut Map,
+    commands: &mut Commands,
+    feature_query: &mut FeatureQuery,
+) -> f32 {
+    let idx = map.tile_xy_idx(plant_pos.x, plant_pos.y);
+    let feature = match map.features[idx] {
+        Some(feature) => feature,
+        None => return 0.0,
+    };
+
+    if let (Some(next_feature), Some(fruit)) = (feature.harvested(), feature.harvest_yield()) {
+        map.features[idx] = Some(next_feature);
+        feature_query.change_feature_tile(plant, next_feature);
+        commands.entity(plant).remove::<Harvestable>().insert(Growing::new());
+        fruit.count as f32 * fruit.item.nutrition()
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,75 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::JobSeeker,
+    map::{world_xy_tile_xy, Map, TILE_SIZE},
+};
+
+use super::components::Destination;
+
+// Angles, in degrees, tried away from straight ahead when the way directly away from the threat is blocked.
+const FLEE_ANGLES: [f32; 7] = [0.0, 30.0, -30.0, 60.0, -60.0, 90.0, -90.0];
+
+#[derive(Component, Clone, Debug)]
+pub struct FleeDestination {
+    //How far, in tiles, to run from the nearest villager
+    pub distance: u32,
+}
+
+pub fn flee_destination(
+    mut commands: Commands,
+    map: Res<Map>,
+    positions: Query<&Transform>,
+    villagers: Query<&Transform, With<JobSeeker>>,
+    mut actions: Query<(&Actor, &mut ActionState, &FleeDestination)>,
+) {
+    for (Actor(actor), mut action_state, flee) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let actor_position = positions.get(*actor).expect("Actor has no position.").translation.xy();
+                let threat = villagers
+                    .iter()
+                    .map(|transform| transform.translation.xy())
+                    .min_by(|a, b| {
+                        a.distance_squared(actor_position)
+                            .partial_cmp(&b.distance_squared(actor_position))
+                            .unwrap()
+                    });
+                let away = match threat {
+                    Some(threat) => (actor_position - threat).try_normalize().unwrap_or(Vec2::X),
+                    None => {
+                        debug!("Nothing to flee from.");
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                };
+
+                let destination = FLEE_ANGLES.iter().find_map(|angle| {
+                    let direction = Vec2::from_angle(angle.to_radians()).rotate(away);
+                    let target = actor_position + direction * flee.distance as f32 * TILE_SIZE.x;
+                    if target.x < 0.0 || target.y < 0.0 {
+                        return None;
+                    }
+                    let tile = world_xy_tile_xy(target);
+                    map.is_passable(tile.x, tile.y).then_some(tile)
+                });
+                if let Some(destination) = destination {
+                    trace!("Setting flee destination.");
+                    commands.entity(*actor).insert(Destination::new(destination, true));
+                    *action_state = ActionState::Success;
+                } else {
+                    debug!("Cornered, nowhere to flee to.");
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
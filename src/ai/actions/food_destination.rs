@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::{
+    items::{Container, Storage},
+    map::Map,
+    zones::Zone,
+};
+
+use super::{components::Destination, shared_eating::FoodPlantQuery};
+
+#[derive(Component, Clone, Debug)]
+pub struct FoodDestination;
+
+pub fn food_destination(
+    mut commands: Commands,
+    map: Res<Map>,
+    storages: Query<(Entity, &Zone, &Container), With<Storage>>,
+    plants: FoodPlantQuery,
+    positions: Query<&Transform>,
+    mut actions: Query<(&Actor, &mut ActionState, &FoodDestination)>,
+) {
+    for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let actor_transform = positions.get(*actor).expect("Actor has no position.");
+                let destination =
+                    super::shared_eating::find_closest_food(storages.iter(), &plants, &map, actor_transform);
+                if let Some((_food, destination)) = destination {
+                    trace!("Setting food destination.");
+                    commands.entity(*actor).insert(Destination::new(destination, true));
+                    *action_state = ActionState::Success;
+                } else {
+                    debug!("Failed to find any food.");
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
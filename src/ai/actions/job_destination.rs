@@ -1,7 +1,11 @@
-use bevy::prelude::*;
+use bevy::{math::Vec3Swizzles, prelude::*};
 use big_brain::prelude::*;
 
-use crate::ai::characteristics::JobSeeker;
+use crate::{
+    ai::{characteristics::JobSeeker, wildlife::Animal},
+    jobs::{job_queue::JobQueue, Jobs},
+    map::world_xy_tile_xy,
+};
 
 use super::components::{Destination, HasJob};
 
@@ -10,7 +14,9 @@ pub struct JobDestination;
 
 pub fn job_destination(
     mut commands: Commands,
+    mut job_queue: ResMut<JobQueue>,
     actors: Query<&HasJob, With<JobSeeker>>,
+    prey: Query<&Transform, With<Animal>>,
     mut actions: Query<(&Actor, &mut ActionState, &JobDestination)>,
 ) {
     for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
@@ -20,10 +26,23 @@ pub fn job_destination(
             }
             ActionState::Executing => {
                 let actor_has_job = actors.get(*actor).expect("Actor should have a job.");
-                let destination = actor_has_job.job.position;
+                let destination = match actor_has_job.job.job_type {
+                    //Animals don't wait around, so hunters head to wherever their prey is now.
+                    Jobs::Hunt(animal) => match prey.get(animal) {
+                        Ok(prey_transform) => world_xy_tile_xy(prey_transform.translation.xy()),
+                        Err(_) => {
+                            debug!("Prey is gone, giving up the hunt.");
+                            job_queue.complete(actor_has_job.job.id);
+                            commands.entity(*actor).remove::<HasJob>();
+                            *action_state = ActionState::Failure;
+                            continue;
+                        }
+                    },
+                    _ => actor_has_job.job.position,
+                };
 
                 // TODO!(3, Wayan, 2): Check that destination is possible.
-                let approximate = !matches!(actor_has_job.job.job_type, crate::jobs::Jobs::Chop);
+                let approximate = !matches!(actor_has_job.job.job_type, Jobs::Chop);
                 commands
                     .entity(*actor)
                     .insert(Destination::new(destination, approximate));
@@ -0,0 +1,60 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    items::{Inventory, Storage},
+    jobs::job_queue::JobQueue,
+    map::world_xy_tile_xy,
+    zones::Zone,
+};
+
+use super::components::{Destination, HasJob};
+
+#[derive(Component, Clone, Debug)]
+pub struct MaterialsDestination;
+
+pub fn materials_destination(
+    mut commands: Commands,
+    job_queue: Res<JobQueue>,
+    storages: Query<&Zone, With<Storage>>,
+    actors: Query<(&Transform, &HasJob, &Inventory)>,
+    mut actions: Query<(&Actor, &mut ActionState, &MaterialsDestination)>,
+) {
+    for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, actor_job, actor_inventory) = actors
+                    .get(*actor)
+                    .expect("Actor should have a position, job and inventory.");
+                let destination =
+                    if super::shared_hauling::materials_to_fetch(&actor_job.job, actor_inventory).is_some() {
+                        let source = job_queue
+                            .material_source(actor_job.job.id)
+                            .and_then(|source| storages.get(source).ok());
+                        match source {
+                            Some(zone) => zone.closest_tile(&world_xy_tile_xy(actor_transform.translation.xy())),
+                            None => {
+                                warn!("Materials for the job aren't stored anywhere.");
+                                *action_state = ActionState::Failure;
+                                continue;
+                            }
+                        }
+                    } else {
+                        //Nothing to fetch, head straight to the job.
+                        actor_job.job.position
+                    };
+
+                trace!("Setting materials destination.");
+                commands.entity(*actor).insert(Destination::new(destination, true));
+                *action_state = ActionState::Success;
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,9 +1,23 @@
+pub mod bed_destination;
+pub mod chat;
 pub mod components;
+pub mod deliver_item;
 pub mod do_job;
 pub mod drink;
+pub mod eat;
+pub mod flee_destination;
+pub mod food_destination;
 pub mod job_destination;
+pub mod materials_destination;
 pub mod move_to_destination;
+pub mod peer_destination;
+pub mod pick_up_item;
+pub mod pick_up_materials;
 pub mod random_destination;
 mod shared_drinking;
+mod shared_eating;
+pub mod shared_hauling;
+pub mod sleep;
+pub mod storage_destination;
 pub mod take_job;
 pub mod water_source_destination;
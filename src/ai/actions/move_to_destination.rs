@@ -4,7 +4,7 @@ use big_brain::prelude::*;
 use hierarchical_pathfinding::{internals::AbstractPath, prelude::Neighborhood};
 
 use crate::{
-    ai::characteristics::Speed,
+    ai::characteristics::{Health, Speed},
     map::{is_neighbor, neighborhood::EuclideanNeighborhood, tile_xy_world_xy, world_xy_tile_xy, Map, MapPathfinding},
 };
 
@@ -20,13 +20,13 @@ pub fn move_to_destination(
     time: Res<Time>,
     map: Res<Map>,
     map_pathfiding: Res<MapPathfinding>,
-    mut query: Query<(&mut Transform, &Destination, &Speed)>,
+    mut query: Query<(&mut Transform, &Destination, &Speed, Option<&Health>)>,
     mut actions: Query<(&Actor, &mut ActionState, &mut MoveToDestination)>,
 ) {
     for (Actor(actor), mut action_state, mut move_to) in actions.iter_mut() {
         match *action_state {
             ActionState::Requested => {
-                let (actor_transform, actor_destination, _actor_speed) =
+                let (actor_transform, actor_destination, _actor_speed, _actor_health) =
                     query.get(*actor).expect("Actor has no position or destination.");
 
                 let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
@@ -96,7 +96,7 @@ pub fn move_to_destination(
                 }
             }
             ActionState::Executing => {
-                let (mut actor_transform, actor_destination, actor_speed) =
+                let (mut actor_transform, actor_destination, actor_speed, actor_health) =
                     query.get_mut(*actor).expect("Actor has no position or destination.");
 
                 let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
@@ -111,10 +111,11 @@ pub fn move_to_destination(
                     if map.is_passable(next_tile.x, next_tile.y) {
                         let next_pos = tile_xy_world_xy(next_tile.x, next_tile.y);
                         let actor_pos = actor_transform.translation.xy();
+                        let speed = actor_speed.speed * actor_health.map_or(1.0, |health| health.speed_multiplier());
                         actor_transform.translation += calculate_step(
                             actor_pos,
                             next_pos,
-                            actor_speed.speed,
+                            speed,
                             map.tile_cost(actor_tile.x, actor_tile.y),
                             time.delta_seconds(),
                         )
@@ -0,0 +1,65 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::social::Relationships,
+    map::{world_xy_tile_xy, TILE_SIZE},
+};
+
+use super::components::{ChatPartner, Destination, HasJob};
+
+// How far, in tiles, villagers look for someone to chat with.
+const SOCIAL_RADIUS: f32 = 15.0;
+// How much closer, in tiles, a friend seems compared to a stranger when choosing who to talk to.
+const FRIEND_PULL: f32 = 10.0;
+
+#[derive(Component, Clone, Debug)]
+pub struct PeerDestination;
+
+pub fn peer_destination(
+    mut commands: Commands,
+    villagers: Query<(Entity, &Transform, &Relationships, Option<&HasJob>)>,
+    mut actions: Query<(&Actor, &mut ActionState, &PeerDestination)>,
+) {
+    for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (_, actor_transform, actor_relationships, _) =
+                    villagers.get(*actor).expect("Actor has no position and relationships.");
+                let actor_position = actor_transform.translation.xy();
+                let peer = villagers
+                    .iter()
+                    .filter(|(peer, _, _, has_job)| peer != actor && has_job.is_none())
+                    .map(|(peer, transform, _, _)| {
+                        let distance = transform.translation.xy().distance(actor_position) / TILE_SIZE.x;
+                        (peer, transform, distance)
+                    })
+                    .filter(|(_, _, distance)| *distance <= SOCIAL_RADIUS)
+                    .min_by(|(a, _, da), (b, _, db)| {
+                        let da = da - FRIEND_PULL * actor_relationships.get(*a) / 100.0;
+                        let db = db - FRIEND_PULL * actor_relationships.get(*b) / 100.0;
+                        da.partial_cmp(&db).unwrap()
+                    });
+
+                if let Some((peer, peer_transform, _)) = peer {
+                    trace!("Setting peer destination.");
+                    let peer_tile = world_xy_tile_xy(peer_transform.translation.xy());
+                    commands
+                        .entity(*actor)
+                        .insert((Destination::new(peer_tile, true), ChatPartner(peer)));
+                    *action_state = ActionState::Success;
+                } else {
+                    debug!("Nobody around to chat with.");
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    items::{Inventory, ItemLocation, ItemQuery, TownInventory},
+    jobs::{job_queue::JobQueue, Jobs},
+    map::{is_neighbor, world_xy_tile_xy},
+};
+
+use super::components::HasJob;
+
+#[derive(Component, Clone, Debug)]
+pub struct PickUpItem;
+
+pub fn pick_up_item(
+    mut commands: Commands,
+    mut job_queue: ResMut<JobQueue>,
+    mut item_query: ItemQuery,
+    mut town_inventory: ResMut<TownInventory>,
+    mut actors: Query<(&Transform, &HasJob, &mut Inventory)>,
+    mut actions: Query<(&Actor, &mut ActionState, &PickUpItem)>,
+) {
+    for (Actor(actor), mut action_state, _pick_up) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, actor_job, mut actor_inventory) = actors
+                    .get_mut(*actor)
+                    .expect("Actor should have a position, job and inventory.");
+                if !actor_inventory.is_empty() {
+                    //Already picked up, we only need to deliver it.
+                    *action_state = ActionState::Success;
+                    continue;
+                }
+
+                let item = match actor_job.job.job_type {
+                    Jobs::Haul(item) => item,
+                    _ => {
+                        error!("Actor tried to pick up an item without a haul job.");
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                };
+
+                let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
+                if let Some(stack) = item_query.get(item).copied() {
+                    if is_neighbor(&actor_tile, &actor_job.job.position) {
+                        let count = u32::min(stack.available(), actor_inventory.capacity_for(stack.item));
+                        if let Some(mut picked_up) = item_query.take(item, count) {
+                            town_inventory.add(picked_up.item, ItemLocation::InTransit, picked_up.count);
+                            actor_inventory.insert(&mut picked_up);
+                        }
+
+                        if count < stack.count {
+                            //Someone will have to come back for the rest.
+                            job_queue.push(Jobs::Haul(item), actor_job.job.position);
+                        }
+                        *action_state = ActionState::Success;
+                    } else {
+                        //We're too far away.
+                        *action_state = ActionState::Failure;
+                    }
+                } else {
+                    warn!("Item to haul no longer exists.");
+                    job_queue.complete(actor_job.job.id);
+                    commands.entity(*actor).remove::<HasJob>();
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
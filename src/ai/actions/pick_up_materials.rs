@@ -0,0 +1,87 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    items::{Container, Inventory, ItemLocation, Storage, TownInventory},
+    jobs::job_queue::JobQueue,
+    map::world_xy_tile_xy,
+    zones::Zone,
+};
+
+use super::components::HasJob;
+
+#[derive(Component, Clone, Debug)]
+pub struct PickUpMaterials;
+
+pub fn pick_up_materials(
+    job_queue: Res<JobQueue>,
+    mut town_inventory: ResMut<TownInventory>,
+    mut storages: Query<(&Zone, &mut Container), With<Storage>>,
+    mut actors: Query<(&Transform, &HasJob, &mut Inventory)>,
+    mut actions: Query<(&Actor, &mut ActionState, &PickUpMaterials)>,
+) {
+    for (Actor(actor), mut action_state, _pick_up) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, actor_job, mut actor_inventory) = actors
+                    .get_mut(*actor)
+                    .expect("Actor should have a position, job and inventory.");
+                let materials = match super::shared_hauling::materials_to_fetch(&actor_job.job, &actor_inventory) {
+                    Some(materials) => materials,
+                    None => {
+                        //Nothing to fetch.
+                        *action_state = ActionState::Success;
+                        continue;
+                    }
+                };
+
+                let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
+                let source = job_queue
+                    .material_source(actor_job.job.id)
+                    .and_then(|source| storages.get_mut(source).ok());
+                match source {
+                    Some((zone, mut container)) if zone.is_neighbor(&actor_tile) => {
+                        if let Some(mut taken) = container.take_reserved(materials.item, materials.count) {
+                            town_inventory.transfer(
+                                taken.item,
+                                taken.count,
+                                ItemLocation::Reserved,
+                                ItemLocation::InTransit,
+                            );
+                            actor_inventory.insert(&mut taken);
+                            if !taken.is_empty() {
+                                warn!("Actor can't carry all the materials for their job.");
+                                //What's left stays set aside for the job.
+                                town_inventory.transfer(
+                                    taken.item,
+                                    taken.count,
+                                    ItemLocation::InTransit,
+                                    ItemLocation::Reserved,
+                                );
+                                let leftover = taken.count;
+                                container.insert(&mut taken);
+                                container.reserve(materials.item, leftover);
+                            }
+                        }
+                        *action_state = ActionState::Success;
+                    }
+                    Some(_) => {
+                        //We're too far away.
+                        *action_state = ActionState::Failure;
+                    }
+                    None => {
+                        warn!("Materials for the job aren't stored anywhere.");
+                        *action_state = ActionState::Failure;
+                    }
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,64 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use bevy_ecs_tilemap::tiles::TilePos;
+
+use crate::{
+    items::{Container, ItemCategory, Items},
+    map::{components::Harvestable, tile_xy_world_xy, world_xy_tile_xy, Features, Map},
+    zones::Zone,
+};
+
+pub type FoodPlantQuery<'w, 's, 'a> = Query<'w, 's, (Entity, &'a TilePos), With<Harvestable>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FoodSource {
+    Storage(Entity),
+    //A fruit-bearing plant, eaten straight from the branch
+    Plant(Entity),
+}
+
+/// The most filling food available in a container.
+pub fn best_food(container: &Container) -> Option<Items> {
+    Items::ALL
+        .into_iter()
+        .filter(|item| item.category() == ItemCategory::Food && container.available(*item) > 0)
+        .max_by(|a, b| a.nutrition().partial_cmp(&b.nutrition()).unwrap())
+}
+
+/// Fruit that can be eaten off a plant. Farmed crops are left to the farmers.
+pub fn plant_food(feature: Features) -> Option<Items> {
+    if feature.is_crop() {
+        return None;
+    }
+
+    feature
+        .harvest_yield()
+        .map(|harvest| harvest.item)
+        .filter(|item| item.category() == ItemCategory::Food)
+}
+
+/// A utility function that finds the closest stored food or fruit-bearing plant to the actor.
+pub fn find_closest_food<'a>(
+    storages: impl Iterator<Item = (Entity, &'a Zone, &'a Container)>,
+    plants: &FoodPlantQuery,
+    map: &Map,
+    actor_position: &Transform,
+) -> Option<(FoodSource, TilePos)> {
+    let actor_tile = world_xy_tile_xy(actor_position.translation.xy());
+    let stored = storages
+        .filter(|(_, _, container)| best_food(container).is_some())
+        .map(|(storage, zone, _)| (FoodSource::Storage(storage), zone.closest_tile(&actor_tile)));
+    let growing = plants
+        .iter()
+        .filter(|(_, tile_pos)| {
+            map.features[map.tile_xy_idx(tile_pos.x, tile_pos.y)]
+                .and_then(plant_food)
+                .is_some()
+        })
+        .map(|(plant, tile_pos)| (FoodSource::Plant(plant), *tile_pos));
+
+    stored.chain(growing).min_by(|(_, a), (_, b)| {
+        let da = tile_xy_world_xy(a.x, a.y).distance_squared(actor_position.translation.xy());
+        let db = tile_xy_world_xy(b.x, b.y).distance_squared(actor_position.translation.xy());
+        da.partial_cmp(&db).unwrap()
+    })
+}
@@ -0,0 +1,70 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use bevy_ecs_tilemap::tiles::TilePos;
+
+use crate::{
+    items::{Container, Inventory, ItemLocation, ItemStack, Items, Storage, TownInventory},
+    jobs::job_queue::{Job, JobQueue},
+    map::tile_xy_world_xy,
+    zones::{Stockpile, Zone},
+};
+
+pub type StorageQuery<'w, 's, 'a> =
+    Query<'w, 's, (Entity, &'a Zone, &'a Container, Option<&'a Stockpile>), With<Storage>>;
+
+/// Whether a storage takes an item and has room for it.
+pub fn accepts(container: &Container, stockpile: Option<&Stockpile>, item: Items) -> bool {
+    stockpile.map_or(true, |stockpile| stockpile.filter.accepts(item)) && container.capacity_for(item) > 0
+}
+
+/// A utility function that finds the closest tile of the closest storage with room for the item.
+pub fn find_closest_storage(
+    storages: &StorageQuery,
+    actor_position: &Transform,
+    item: Items,
+) -> Option<(Entity, TilePos)> {
+    let actor_tile = crate::map::world_xy_tile_xy(actor_position.translation.xy());
+    storages
+        .iter()
+        .filter(|(_, _, container, stockpile)| accepts(container, *stockpile, item))
+        .map(|(entity, zone, _, _)| (entity, zone.closest_tile(&actor_tile)))
+        .min_by(|(_, a), (_, b)| {
+            let da = tile_xy_world_xy(a.x, a.y).distance_squared(actor_position.translation.xy());
+            let db = tile_xy_world_xy(b.x, b.y).distance_squared(actor_position.translation.xy());
+            da.partial_cmp(&db).unwrap()
+        })
+}
+
+/// The materials the actor still has to fetch from storage before doing their job.
+pub fn materials_to_fetch(job: &Job, inventory: &Inventory) -> Option<ItemStack> {
+    job.materials
+        .filter(|materials| inventory.count(materials.item) < materials.count)
+        .map(|materials| ItemStack::new(materials.item, materials.count - inventory.count(materials.item)))
+}
+
+/// Frees the materials still set aside in storage for a job, so they can be reserved again once it's requeued.
+pub fn release_materials(
+    job: &Job,
+    inventory: Option<&Inventory>,
+    job_queue: &JobQueue,
+    storages: &mut Query<&mut Container, With<Storage>>,
+    town_inventory: &mut TownInventory,
+) {
+    let materials = match job.materials {
+        Some(materials) => materials,
+        None => return,
+    };
+    let carried = inventory.map_or(0, |inventory| inventory.count(materials.item));
+    let still_reserved = materials.count.saturating_sub(carried);
+    let source = job_queue
+        .material_source(job.id)
+        .and_then(|source| storages.get_mut(source).ok());
+    if let Some(mut container) = source.filter(|_| still_reserved > 0) {
+        container.unreserve(materials.item, still_reserved);
+        town_inventory.transfer(
+            materials.item,
+            still_reserved,
+            ItemLocation::Reserved,
+            ItemLocation::Stored,
+        );
+    }
+}
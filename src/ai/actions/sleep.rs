@@ -0,0 +1,64 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::{energy::Energy, home::Home, MoodEvent, MoodEventOccurred},
+    map::{is_neighbor, world_xy_tile_xy},
+};
+
+// How much slower villagers recover when sleeping on the ground instead of in their bed.
+const ROUGH_SLEEP_MULTIPLIER: f32 = 0.5;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Sleep {
+    pub per_second: f32,
+}
+
+pub fn sleep(
+    time: Res<Time>,
+    mut sleepers: Query<(&Transform, &mut Energy, Option<&Home>)>,
+    mut actions: Query<(&Actor, &mut ActionState, &Sleep)>,
+    mut mood_events: EventWriter<MoodEventOccurred>,
+) {
+    for (Actor(actor), mut action_state, sleep) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, mut actor_energy, actor_home) =
+                    sleepers.get_mut(*actor).expect("Actor has no position and energy.");
+                let actor_tile = world_xy_tile_xy(actor_transform.translation.xy());
+                let multiplier = match actor_home {
+                    Some(home) if is_neighbor(&actor_tile, &home.position) => 1.0,
+                    Some(_) => {
+                        //We're too far away from our bed.
+                        *action_state = ActionState::Failure;
+                        continue;
+                    }
+                    None => ROUGH_SLEEP_MULTIPLIER,
+                };
+
+                //Energy keeps draining while asleep, so make up for it.
+                actor_energy.energy += (sleep.per_second * multiplier + actor_energy.per_second) * time.delta_seconds();
+                if actor_energy.energy >= 100.0 {
+                    actor_energy.energy = 100.0;
+                    let event = if actor_home.is_some() {
+                        MoodEvent::SleptInBed
+                    } else {
+                        MoodEvent::SleptOutside
+                    };
+                    mood_events.send(MoodEventOccurred {
+                        villager: *actor,
+                        event,
+                    });
+                    *action_state = ActionState::Success;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
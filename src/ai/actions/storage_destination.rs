@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::items::{Inventory, Storage};
+
+use super::{components::Destination, shared_hauling::StorageQuery};
+
+#[derive(Component, Clone, Debug)]
+pub struct StorageDestination;
+
+pub fn storage_destination(
+    mut commands: Commands,
+    storages: StorageQuery,
+    actors: Query<(&Transform, &Inventory), Without<Storage>>,
+    mut actions: Query<(&Actor, &mut ActionState, &StorageDestination)>,
+) {
+    for (Actor(actor), mut action_state, _move_to) in actions.iter_mut() {
+        match *action_state {
+            ActionState::Requested => {
+                *action_state = ActionState::Executing;
+            }
+            ActionState::Executing => {
+                let (actor_transform, actor_inventory) =
+                    actors.get(*actor).expect("Actor has no position or inventory.");
+                let destination = actor_inventory.stacks().first().and_then(|stack| {
+                    super::shared_hauling::find_closest_storage(&storages, actor_transform, stack.item)
+                });
+                if let Some((_storage, destination)) = destination {
+                    trace!("Setting storage destination.");
+                    commands.entity(*actor).insert(Destination::new(destination, true));
+                    *action_state = ActionState::Success;
+                } else {
+                    error!("Failed to find a storage destination.");
+                    *action_state = ActionState::Failure;
+                }
+            }
+            ActionState::Cancelled => {
+                *action_state = ActionState::Failure;
+            }
+            _ => {}
+        }
+    }
+}
@@ -1,38 +1,84 @@
 use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, RngComponent};
 use big_brain::prelude::*;
 
-use crate::{ai::characteristics::JobSeeker, jobs::job_queue::JobQueue};
+use crate::{
+    ai::characteristics::{social::Relationships, JobSeeker, Mood, RefusedWork, Skills},
+    jobs::{job_queue::JobQueue, JobCategory},
+};
 
 use super::components::HasJob;
 
+// How close, in tiles, a friend has to be working for a job to count as working alongside them.
+const WORK_NEAR_FRIENDS_DISTANCE: u32 = 10;
+// How much more attractive a job next to a friend is.
+const WORK_NEAR_FRIENDS_BONUS: f32 = 0.25;
+
 #[derive(Component, Clone, Debug)]
-pub struct TakingJob;
+pub struct TakingJob {
+    pub category: JobCategory,
+}
+
+impl TakingJob {
+    pub fn new(category: JobCategory) -> Self {
+        Self { category }
+    }
+}
 
 pub fn take_job(
     mut commands: Commands,
     mut job_queue: ResMut<JobQueue>,
-    actors: Query<Option<&HasJob>, With<JobSeeker>>,
+    mut actors: Query<(Option<&HasJob>, &Skills, &Mood, &Relationships, &mut RngComponent), With<JobSeeker>>,
+    workers: Query<&HasJob>,
     mut actions: Query<(&Actor, &mut ActionState, &TakingJob)>,
 ) {
-    for (Actor(actor), mut action_state, _taking_job) in actions.iter_mut() {
+    for (Actor(actor), mut action_state, taking_job) in actions.iter_mut() {
         match *action_state {
             ActionState::Requested => {
                 *action_state = ActionState::Executing;
             }
             ActionState::Executing => {
-                let actor_has_job = actors.get(*actor).expect("Actor should have JobSeeker.");
-                if actor_has_job.is_none() {
+                let (actor_has_job, actor_skills, actor_mood, actor_relationships, mut actor_rng) =
+                    actors.get_mut(*actor).expect("Actor should have JobSeeker.");
+                if let Some(actor_has_job) = actor_has_job {
+                    if actor_has_job.job.job_type.category() == taking_job.category {
+                        warn!("Actor already has a job.");
+                        *action_state = ActionState::Success;
+                    } else {
+                        *action_state = ActionState::Failure;
+                    }
+                } else if actor_rng.chance(actor_mood.refusal_chance() as f64) {
+                    //Too unhappy to work right now, they'll do something else for a while.
+                    commands.entity(*actor).insert(RefusedWork::default());
+                    *action_state = ActionState::Failure;
+                } else {
                     // TODO!(2, Wayan, 1): Improve job selection to incorporate distance to actor to prioritise jobs.
-                    let job = job_queue.jobs.pop_front();
+                    let friend_jobs: Vec<_> = actor_relationships
+                        .friends()
+                        .filter_map(|friend| workers.get(friend).ok())
+                        .map(|has_job| has_job.job.position)
+                        .collect();
+                    let job = job_queue.take_preferred(taking_job.category, |job| {
+                        let skill = job
+                            .job_type
+                            .skill()
+                            .map_or(1.0, |skill| actor_skills.work_multiplier(skill));
+                        let near_friend = friend_jobs.iter().any(|position| {
+                            position.x.abs_diff(job.position.x) + position.y.abs_diff(job.position.y)
+                                <= WORK_NEAR_FRIENDS_DISTANCE
+                        });
+                        if near_friend {
+                            skill * (1.0 + WORK_NEAR_FRIENDS_BONUS)
+                        } else {
+                            skill
+                        }
+                    });
                     if let Some(job) = job {
                         commands.entity(*actor).insert(HasJob::new(job));
                         *action_state = ActionState::Success;
                     } else {
                         *action_state = ActionState::Failure;
                     }
-                } else {
-                    warn!("Actor already has a job.");
-                    *action_state = ActionState::Success;
                 }
             }
             ActionState::Cancelled => {
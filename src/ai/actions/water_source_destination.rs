@@ -2,7 +2,7 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::tiles::TilePos;
 use big_brain::prelude::*;
 
-use crate::map::components::WaterSource;
+use crate::map::components::{WaterSource, WaterSourceCapacity};
 
 use super::components::Destination;
 
@@ -11,7 +11,8 @@ pub struct WaterSourceDestination;
 
 pub fn water_source_destination(
     mut commands: Commands,
-    water_sources: Query<&TilePos, With<WaterSource>>,
+    water_sources: Query<(Entity, &TilePos), With<WaterSource>>,
+    capacities: Query<&WaterSourceCapacity>,
     positions: Query<&Transform, Without<WaterSource>>,
     mut actions: Query<(&Actor, &mut ActionState, &WaterSourceDestination)>,
 ) {
@@ -22,11 +23,16 @@ pub fn water_source_destination(
             }
             ActionState::Executing => {
                 let actor_transform = positions.get(*actor).expect("Actor has no position.");
-                let destination = Some(super::shared_drinking::find_closest_water_source(
+                let destination = super::shared_drinking::find_closest_water_source(
                     &water_sources,
+                    |water| {
+                        capacities
+                            .get(water)
+                            .map_or(true, |capacity| capacity.has_room_for(*actor))
+                    },
                     actor_transform,
-                ));
-                if let Some(destination) = destination {
+                );
+                if let Some((_water, destination)) = destination {
                     trace!("Setting water source destination.");
                     commands.entity(*actor).insert(Destination::new(destination, true));
                     *action_state = ActionState::Success;
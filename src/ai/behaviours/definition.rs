@@ -0,0 +1,268 @@
+use bevy::{prelude::*, reflect::TypeUuid};
+use bevy_turborand::RngComponent;
+use big_brain::{actions::ActionBuilder, prelude::*, scorers::ScorerBuilder};
+use serde::Deserialize;
+
+use crate::{
+    ai::{
+        actions::{
+            bed_destination::BedDestination,
+            chat::Chat,
+            components::{ActionLabel, PickedOption},
+            deliver_item::DeliverItem,
+            do_job::DoJob,
+            drink::Drink,
+            eat::Eat,
+            flee_destination::FleeDestination,
+            food_destination::FoodDestination,
+            job_destination::JobDestination,
+            materials_destination::MaterialsDestination,
+            move_to_destination::MoveToDestination,
+            peer_destination::PeerDestination,
+            pick_up_item::PickUpItem,
+            pick_up_materials::PickUpMaterials,
+            random_destination::RandomDestinationBuilder,
+            sleep::Sleep,
+            storage_destination::StorageDestination,
+            take_job::TakingJob,
+            water_source_destination::WaterSourceDestination,
+        },
+        pickers::{highest_score::HighestScore, sticky::Sticky, weighted_random::WeightedRandom},
+        scorers::{
+            components::ScorerLabel, hungry::Hungry, job_available::JobAvailable, lonely::Lonely,
+            response_curve::ResponseCurve, thirsty::Thirsty, threatened::Threatened, tired::Tired,
+        },
+    },
+    jobs::JobCategory,
+    SIMULATION_SPEED,
+};
+
+/// A villager thinker described in a `.behaviour.ron` asset.
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "5c3f0e54-8d8a-4a47-9b0c-2f4d6a1e7b93"]
+pub struct BehaviourDefinition {
+    pub archetype: String,
+    //Relative chance for a new villager to get this behaviour, 0 for behaviours only animals use
+    pub weight: u32,
+    pub picker: PickerDefinition,
+    pub options: Vec<OptionDefinition>,
+}
+
+impl BehaviourDefinition {
+    /// Builds the thinker of one villager, seeding any random picker from the villager's generator.
+    pub fn thinker(&self, rng: &mut RngComponent) -> ThinkerBuilder {
+        let thinker = match self.picker {
+            PickerDefinition::HighestScore => Thinker::build().picker(HighestScore::new()),
+            PickerDefinition::WeightedRandom { threshold } => {
+                Thinker::build().picker(WeightedRandom::new(threshold, rng))
+            }
+            PickerDefinition::Sticky { margin } => Thinker::build().picker(Sticky::new(margin)),
+        };
+        self.options.iter().fold(thinker, |thinker, option| {
+            thinker.when(
+                CurvedScorer {
+                    scorer: option.when.clone(),
+                    curve: option.curve.clone(),
+                },
+                PickedAction {
+                    scorer: option.when.name(),
+                    action: option.then.clone(),
+                },
+            )
+        })
+    }
+}
+
+/// An action to take whenever its scorer wins.
+#[derive(Clone, Debug, Deserialize)]
+pub struct OptionDefinition {
+    pub when: ScorerDefinition,
+    //Reshapes the need measured by the scorer before it becomes a score
+    #[serde(default)]
+    pub curve: Option<ResponseCurve>,
+    pub then: ActionDefinition,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+pub enum PickerDefinition {
+    HighestScore,
+    WeightedRandom { threshold: f32 },
+    Sticky { margin: f32 },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub enum ScorerDefinition {
+    Thirsty,
+    Hungry,
+    Tired { has_home: bool },
+    Lonely,
+    JobAvailable(JobCategory),
+    //How close a villager is, for animals within `radius` tiles of one
+    Threatened { radius: f32 },
+    Fixed(f32),
+}
+
+impl ScorerDefinition {
+    /// What the scorer is called when tracing villagers' decisions.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScorerDefinition::Thirsty => "Thirsty",
+            ScorerDefinition::Hungry => "Hungry",
+            ScorerDefinition::Tired { has_home: true } => "Tired at home",
+            ScorerDefinition::Tired { has_home: false } => "Tired outside",
+            ScorerDefinition::Lonely => "Lonely",
+            ScorerDefinition::JobAvailable(JobCategory::Work) => "Work available",
+            ScorerDefinition::JobAvailable(JobCategory::Haul) => "Haul available",
+            ScorerDefinition::Threatened { .. } => "Threatened",
+            ScorerDefinition::Fixed(_) => "Fixed",
+        }
+    }
+}
+
+/// A scorer along with the response curve shaping its score.
+#[derive(Clone, Debug)]
+struct CurvedScorer {
+    scorer: ScorerDefinition,
+    curve: Option<ResponseCurve>,
+}
+
+impl ScorerBuilder for CurvedScorer {
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        self.scorer.build(cmd, scorer, actor);
+        cmd.entity(scorer).insert(ScorerLabel(self.scorer.name()));
+        if let Some(curve) = &self.curve {
+            cmd.entity(scorer).insert(curve.clone());
+        }
+    }
+}
+
+/// The action of an option, marked with the scorer that got it picked.
+#[derive(Clone, Debug)]
+struct PickedAction {
+    scorer: &'static str,
+    action: ActionDefinition,
+}
+
+impl ActionBuilder for PickedAction {
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        self.action.build(cmd, action, actor);
+        cmd.entity(action).insert(PickedOption(self.scorer));
+    }
+}
+
+impl ScorerBuilder for ScorerDefinition {
+    fn build(&self, cmd: &mut Commands, scorer: Entity, actor: Entity) {
+        match self {
+            ScorerDefinition::Thirsty => Thirsty.build(cmd, scorer, actor),
+            ScorerDefinition::Hungry => Hungry.build(cmd, scorer, actor),
+            ScorerDefinition::Tired { has_home } => Tired::new(*has_home).build(cmd, scorer, actor),
+            ScorerDefinition::Lonely => Lonely.build(cmd, scorer, actor),
+            ScorerDefinition::JobAvailable(category) => JobAvailable::new(*category).build(cmd, scorer, actor),
+            ScorerDefinition::Threatened { radius } => Threatened::new(*radius).build(cmd, scorer, actor),
+            ScorerDefinition::Fixed(score) => FixedScore(*score).build(cmd, scorer, actor),
+        }
+    }
+}
+
+/// Rates are given per second of game time, and scaled by the simulation speed when built.
+#[derive(Clone, Debug, Deserialize)]
+pub enum ActionDefinition {
+    Steps(Vec<ActionDefinition>),
+    TakeJob(JobCategory),
+    JobDestination,
+    MaterialsDestination,
+    StorageDestination,
+    WaterSourceDestination,
+    FoodDestination,
+    BedDestination,
+    PeerDestination,
+    //A tile `distance` tiles away from the nearest villager
+    FleeDestination { distance: u32 },
+    RandomDestination,
+    MoveToDestination,
+    PickUpMaterials,
+    PickUpItem,
+    DeliverItem,
+    DoJob,
+    Drink { per_second: f32 },
+    Eat { per_second: f32 },
+    Sleep { per_second: f32 },
+    Chat { per_second: f32 },
+}
+
+impl ActionDefinition {
+    /// What the action is called when showing what a villager is doing, steps are only named through their parts.
+    pub fn name(&self) -> Option<&'static str> {
+        let name = match self {
+            ActionDefinition::Steps(_) => return None,
+            ActionDefinition::TakeJob(_) => "Taking a job",
+            ActionDefinition::JobDestination => "Heading to work",
+            ActionDefinition::MaterialsDestination => "Fetching materials",
+            ActionDefinition::StorageDestination => "Heading to storage",
+            ActionDefinition::WaterSourceDestination => "Looking for water",
+            ActionDefinition::FoodDestination => "Looking for food",
+            ActionDefinition::BedDestination => "Heading to bed",
+            ActionDefinition::PeerDestination => "Looking for company",
+            ActionDefinition::FleeDestination { .. } => "Fleeing",
+            ActionDefinition::RandomDestination => "Wandering",
+            ActionDefinition::MoveToDestination => "Walking",
+            ActionDefinition::PickUpMaterials => "Picking up materials",
+            ActionDefinition::PickUpItem => "Picking up an item",
+            ActionDefinition::DeliverItem => "Delivering an item",
+            ActionDefinition::DoJob => "Working",
+            ActionDefinition::Drink { .. } => "Drinking",
+            ActionDefinition::Eat { .. } => "Eating",
+            ActionDefinition::Sleep { .. } => "Sleeping",
+            ActionDefinition::Chat { .. } => "Chatting",
+        };
+        Some(name)
+    }
+}
+
+impl ActionBuilder for ActionDefinition {
+    fn build(&self, cmd: &mut Commands, action: Entity, actor: Entity) {
+        if let Some(name) = self.name() {
+            cmd.entity(action).insert(ActionLabel(name));
+        }
+
+        match self {
+            ActionDefinition::Steps(steps) => steps
+                .iter()
+                .fold(Steps::build(), |builder, step| builder.step(step.clone()))
+                .build(cmd, action, actor),
+            ActionDefinition::TakeJob(category) => TakingJob::new(*category).build(cmd, action, actor),
+            ActionDefinition::JobDestination => JobDestination.build(cmd, action, actor),
+            ActionDefinition::MaterialsDestination => MaterialsDestination.build(cmd, action, actor),
+            ActionDefinition::StorageDestination => StorageDestination.build(cmd, action, actor),
+            ActionDefinition::WaterSourceDestination => WaterSourceDestination.build(cmd, action, actor),
+            ActionDefinition::FoodDestination => FoodDestination.build(cmd, action, actor),
+            ActionDefinition::BedDestination => BedDestination.build(cmd, action, actor),
+            ActionDefinition::PeerDestination => PeerDestination.build(cmd, action, actor),
+            ActionDefinition::FleeDestination { distance } => {
+                FleeDestination { distance: *distance }.build(cmd, action, actor)
+            }
+            ActionDefinition::RandomDestination => RandomDestinationBuilder.build(cmd, action, actor),
+            ActionDefinition::MoveToDestination => MoveToDestination::default().build(cmd, action, actor),
+            ActionDefinition::PickUpMaterials => PickUpMaterials.build(cmd, action, actor),
+            ActionDefinition::PickUpItem => PickUpItem.build(cmd, action, actor),
+            ActionDefinition::DeliverItem => DeliverItem.build(cmd, action, actor),
+            ActionDefinition::DoJob => DoJob.build(cmd, action, actor),
+            ActionDefinition::Drink { per_second } => Drink {
+                per_second: per_second * SIMULATION_SPEED,
+            }
+            .build(cmd, action, actor),
+            ActionDefinition::Eat { per_second } => Eat {
+                per_second: per_second * SIMULATION_SPEED,
+            }
+            .build(cmd, action, actor),
+            ActionDefinition::Sleep { per_second } => Sleep {
+                per_second: per_second * SIMULATION_SPEED,
+            }
+            .build(cmd, action, actor),
+            ActionDefinition::Chat { per_second } => Chat {
+                per_second: per_second * SIMULATION_SPEED,
+            }
+            .build(cmd, action, actor),
+        }
+    }
+}
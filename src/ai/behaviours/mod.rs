@@ -0,0 +1,113 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use bevy_turborand::{DelegatedRng, RngComponent};
+
+use super::wildlife::Species;
+
+mod definition;
+mod loader;
+
+pub use definition::*;
+pub use loader::BehaviourLoader;
+
+#[derive(Resource, AssetCollection)]
+pub struct BehaviourAssets {
+    #[asset(path = "behaviours", collection(typed))]
+    behaviours: Vec<Handle<BehaviourDefinition>>,
+}
+
+impl BehaviourAssets {
+    fn all<'a>(&self, definitions: &'a Assets<BehaviourDefinition>) -> Vec<&'a BehaviourDefinition> {
+        self.behaviours
+            .iter()
+            .map(|handle| {
+                definitions
+                    .get(handle)
+                    .expect("Behaviours should be loaded before spawning.")
+            })
+            .collect()
+    }
+
+    /// The behaviour shared by every animal of a species, never given to villagers.
+    pub fn wildlife<'a>(
+        &self,
+        definitions: &'a Assets<BehaviourDefinition>,
+        species: Species,
+    ) -> &'a BehaviourDefinition {
+        self.all(definitions)
+            .into_iter()
+            .find(|definition| definition.archetype == species.name())
+            .unwrap_or_else(|| panic!("{} should have a behaviour.", species.name()))
+    }
+
+    /// Picks one of the villager behaviours at random, according to their weights.
+    /// None if no behaviour can be given to villagers.
+    pub fn choose<'a>(
+        &self,
+        definitions: &'a Assets<BehaviourDefinition>,
+        rng: &mut RngComponent,
+    ) -> Option<&'a BehaviourDefinition> {
+        pick_weighted(self.all(definitions), rng)
+    }
+}
+
+fn pick_weighted<'a>(
+    definitions: Vec<&'a BehaviourDefinition>,
+    rng: &mut RngComponent,
+) -> Option<&'a BehaviourDefinition> {
+    let total: u32 = definitions.iter().map(|definition| definition.weight).sum();
+    if total == 0 {
+        return None;
+    }
+
+    let mut roll = rng.u32(0..total);
+    definitions.into_iter().find(|definition| {
+        if roll < definition.weight {
+            true
+        } else {
+            roll -= definition.weight;
+            false
+        }
+    })
+}
+
+/// Name of the behaviour a villager was given.
+#[derive(Component, Clone, Debug)]
+pub struct Archetype(pub String);
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn shipped_behaviours_deserialize() {
+        let folder = concat!(env!("CARGO_MANIFEST_DIR"), "/assets/behaviours");
+        let definitions: Vec<BehaviourDefinition> = fs::read_dir(folder)
+            .expect("Behaviours folder should exist.")
+            .map(|entry| {
+                let path = entry.expect("Behaviour file should be readable.").path();
+                let bytes = fs::read(&path).expect("Behaviour file should be readable.");
+                ron::de::from_bytes(&bytes).unwrap_or_else(|error| panic!("{} is invalid: {}", path.display(), error))
+            })
+            .collect();
+
+        assert!(definitions.iter().any(|definition| definition.weight > 0));
+        for species in Species::ALL {
+            assert!(definitions
+                .iter()
+                .any(|definition| definition.archetype == species.name()));
+        }
+    }
+
+    #[test]
+    fn animal_only_behaviours_are_never_picked() {
+        let definition: BehaviourDefinition =
+            ron::de::from_str(r#"(archetype: "Deer", weight: 0, picker: HighestScore, options: [])"#)
+                .expect("Behaviour should deserialize.");
+        let mut rng = RngComponent::with_seed(0);
+        assert!(pick_weighted(vec![&definition], &mut rng).is_none());
+        assert!(pick_weighted(Vec::new(), &mut rng).is_none());
+    }
+}
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Energy {
+    //Rate of energy loss while awake
+    pub per_second: f32,
+    //Current energy, 100 when fully rested
+    pub energy: f32,
+}
+
+impl Energy {
+    pub fn new(energy: f32, per_second: f32) -> Self {
+        Self { per_second, energy }
+    }
+
+    /// Exhausted villagers work at half the speed of rested ones.
+    pub fn work_multiplier(&self) -> f32 {
+        0.5 + 0.5 * self.energy / 100.0
+    }
+}
+
+pub fn handle_energy(time: Res<Time>, mut energies: Query<&mut Energy>) {
+    energies.par_for_each_mut(100, |mut energy| {
+        energy.energy -= energy.per_second * time.delta_seconds();
+
+        //Clamp to 0.0
+        energy.energy = f32::max(energy.energy, 0.0);
+    });
+}
@@ -0,0 +1,139 @@
+use bevy::prelude::*;
+
+use crate::SIMULATION_SPEED;
+
+use super::{Energy, Hunger, Thirst};
+
+// Need level from which villagers start to feel it.
+const DISCOMFORT_LEVEL: f32 = 70.0;
+// Need level from which villagers can barely function.
+const SUFFERING_LEVEL: f32 = 90.0;
+// Health lost every second for each need that has been left completely unmet.
+const DAMAGE_PER_SECOND: f32 = 0.5 * SIMULATION_SPEED;
+// Health regained every second while no need is pressing.
+const HEALING_PER_SECOND: f32 = 0.2 * SIMULATION_SPEED;
+
+/// How badly a villager is affected by their most pressing need, from least to most severe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Condition {
+    Healthy,
+    Uncomfortable,
+    Suffering,
+    Critical,
+}
+
+impl Condition {
+    pub fn from_need(need: f32) -> Self {
+        if need >= 100.0 {
+            Condition::Critical
+        } else if need >= SUFFERING_LEVEL {
+            Condition::Suffering
+        } else if need >= DISCOMFORT_LEVEL {
+            Condition::Uncomfortable
+        } else {
+            Condition::Healthy
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Condition::Healthy => "Healthy",
+            Condition::Uncomfortable => "Uncomfortable",
+            Condition::Suffering => "Suffering",
+            Condition::Critical => "Critical",
+        }
+    }
+
+    pub fn work_multiplier(&self) -> f32 {
+        match self {
+            Condition::Healthy => 1.0,
+            Condition::Uncomfortable => 0.8,
+            Condition::Suffering => 0.5,
+            Condition::Critical => 0.25,
+        }
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            Condition::Healthy | Condition::Uncomfortable => 1.0,
+            Condition::Suffering => 0.75,
+            Condition::Critical => 0.5,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Health {
+    //Current health, the villager dies when it reaches 0
+    pub health: f32,
+    //How much the villager's needs are holding them back
+    pub condition: Condition,
+}
+
+impl Health {
+    pub fn new(health: f32) -> Self {
+        Self {
+            health,
+            condition: Condition::Healthy,
+        }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.health <= 0.0
+    }
+
+    pub fn work_multiplier(&self) -> f32 {
+        self.condition.work_multiplier()
+    }
+
+    pub fn speed_multiplier(&self) -> f32 {
+        self.condition.speed_multiplier()
+    }
+}
+
+/// How much health changes per second given a villager's thirst, hunger and exhaustion.
+fn health_change(thirst: f32, hunger: f32, exhaustion: f32) -> f32 {
+    let unmet = [thirst, hunger].into_iter().filter(|need| *need >= 100.0).count();
+    if unmet > 0 {
+        -DAMAGE_PER_SECOND * unmet as f32
+    } else if Condition::from_need(thirst.max(hunger).max(exhaustion)) == Condition::Healthy {
+        HEALING_PER_SECOND
+    } else {
+        0.0
+    }
+}
+
+pub fn handle_health(time: Res<Time>, mut villagers: Query<(&mut Health, &Thirst, &Hunger, Option<&Energy>)>) {
+    villagers.par_for_each_mut(100, |(mut health, thirst, hunger, energy)| {
+        let exhaustion = energy.map_or(0.0, |energy| 100.0 - energy.energy);
+        health.condition = Condition::from_need(thirst.thirst.max(hunger.hunger).max(exhaustion));
+        health.health += health_change(thirst.thirst, hunger.hunger, exhaustion) * time.delta_seconds();
+
+        //Clamp to 0.0..100.0
+        health.health = health.health.clamp(0.0, 100.0);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn condition_escalates_with_need() {
+        assert_eq!(Condition::from_need(0.0), Condition::Healthy);
+        assert_eq!(Condition::from_need(DISCOMFORT_LEVEL), Condition::Uncomfortable);
+        assert_eq!(Condition::from_need(SUFFERING_LEVEL), Condition::Suffering);
+        assert_eq!(Condition::from_need(100.0), Condition::Critical);
+        assert!(Condition::Critical.work_multiplier() < Condition::Suffering.work_multiplier());
+    }
+
+    #[test]
+    fn unmet_needs_cause_damage() {
+        assert_eq!(health_change(10.0, 10.0, 10.0), HEALING_PER_SECOND);
+        assert_eq!(health_change(80.0, 10.0, 10.0), 0.0);
+        assert_eq!(health_change(100.0, 10.0, 10.0), -DAMAGE_PER_SECOND);
+        assert_eq!(health_change(100.0, 100.0, 10.0), -2.0 * DAMAGE_PER_SECOND);
+        //Exhaustion slows villagers down, but doesn't kill them.
+        assert_eq!(health_change(10.0, 10.0, 100.0), 0.0);
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::map::{components::Bed, Map};
+
+use super::{social::Relationships, Energy};
+
+// The largest room a bed can be in and still count as a home.
+const MAX_HOME_TILES: usize = 100;
+// How close, in tiles, a friend's bed has to be for villagers to want to move in next to them.
+const ROOMMATE_DISTANCE: u32 = 8;
+
+/// The bed a villager goes back to when they need sleep.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Home {
+    pub bed: Entity,
+    pub position: TilePos,
+}
+
+/// Works out which beds are in a room, only when beds are placed or the map's features change.
+pub fn find_bed_rooms(map: Res<Map>, mut beds: Query<(&TilePos, &mut Bed, ChangeTrackers<Bed>)>) {
+    for (bed_pos, mut bed, bed_tracker) in beds.iter_mut() {
+        if !map.is_changed() && !bed_tracker.is_added() {
+            continue;
+        }
+
+        let in_room = map.is_enclosed(bed_pos, MAX_HOME_TILES);
+        if bed.in_room != in_room {
+            bed.in_room = in_room;
+        }
+    }
+}
+
+pub fn assign_homes(
+    mut commands: Commands,
+    mut beds: Query<(Entity, &TilePos, &mut Bed)>,
+    homes: Query<(Entity, &Home)>,
+    homeless: Query<(Entity, Option<&Relationships>), (With<Energy>, Without<Home>)>,
+) {
+    for (villager, home) in homes.iter() {
+        if !beds.contains(home.bed) {
+            //The bed has been deconstructed.
+            commands.entity(villager).remove::<Home>();
+        }
+    }
+
+    if homeless.is_empty() {
+        return;
+    }
+
+    let mut free_beds: Vec<_> = beds
+        .iter()
+        .filter(|(bed_entity, _, bed)| {
+            !bed.owner
+                .and_then(|owner| homes.get(owner).ok())
+                .map_or(false, |(_, home)| home.bed == *bed_entity)
+        })
+        .filter(|(_, _, bed)| bed.in_room)
+        .map(|(bed_entity, bed_pos, _)| (bed_entity, *bed_pos))
+        .collect();
+    if free_beds.is_empty() {
+        return;
+    }
+
+    //Where everyone sleeps, including the homes handed out below.
+    let mut bed_positions: HashMap<Entity, TilePos> =
+        homes.iter().map(|(villager, home)| (villager, home.position)).collect();
+    for (villager, relationships) in homeless.iter() {
+        //Move in next to as many friends as possible.
+        let friends_nearby = |bed_pos: &TilePos| {
+            relationships.map_or(0, |relationships| {
+                relationships
+                    .friends()
+                    .filter_map(|friend| bed_positions.get(&friend))
+                    .filter(|friend_pos| {
+                        friend_pos.x.abs_diff(bed_pos.x) + friend_pos.y.abs_diff(bed_pos.y) <= ROOMMATE_DISTANCE
+                    })
+                    .count()
+            })
+        };
+        let best = free_beds
+            .iter()
+            .enumerate()
+            .max_by_key(|(idx, (_, bed_pos))| (friends_nearby(bed_pos), std::cmp::Reverse(*idx)))
+            .map(|(idx, _)| idx);
+        let (bed_entity, bed_pos) = match best {
+            Some(idx) => free_beds.remove(idx),
+            None => break,
+        };
+
+        if let Ok((_, _, mut bed)) = beds.get_mut(bed_entity) {
+            bed.owner = Some(villager);
+        }
+        bed_positions.insert(villager, bed_pos);
+        commands.entity(villager).insert(Home {
+            bed: bed_entity,
+            position: bed_pos,
+        });
+    }
+}
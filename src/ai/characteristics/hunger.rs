@@ -0,0 +1,30 @@
+use bevy::prelude::*;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Hunger {
+    //Rate of hunger accumulation
+    pub per_second: f32,
+    //Current eating progress
+    pub eat_progress: f32,
+    //Current hunger
+    pub hunger: f32,
+}
+
+impl Hunger {
+    pub fn new(hunger: f32, per_second: f32) -> Self {
+        Self {
+            per_second,
+            hunger,
+            eat_progress: 0.0,
+        }
+    }
+}
+
+pub fn handle_hunger(time: Res<Time>, mut hungers: Query<&mut Hunger>) {
+    hungers.par_for_each_mut(100, |mut hunger| {
+        hunger.hunger += hunger.per_second * time.delta_seconds();
+
+        //Clamp to 100.0
+        hunger.hunger = f32::min(hunger.hunger, 100.0);
+    });
+}
@@ -1,7 +1,23 @@
+pub mod energy;
+pub mod health;
+pub mod home;
+pub mod hunger;
 pub mod job_seeker;
+pub mod mood;
+pub mod schedule;
+pub mod skills;
+pub mod social;
 pub mod speed;
 pub mod thirst;
 
+pub use energy::*;
+pub use health::*;
+pub use home::*;
+pub use hunger::*;
 pub use job_seeker::*;
+pub use mood::*;
+pub use schedule::*;
+pub use skills::*;
+pub use social::*;
 pub use speed::*;
 pub use thirst::*;
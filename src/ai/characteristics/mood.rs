@@ -0,0 +1,296 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+use crate::{
+    ai::actions::components::HasJob,
+    map::{world_xy_tile_xy, Map},
+};
+
+use super::{
+    social::{Loneliness, Relationships},
+    Energy, Home, Hunger, Thirst,
+};
+
+// Mood of a villager with every modifier at 0.
+const BASE_MOOD: f32 = 50.0;
+// How quickly mood follows its modifiers, as a fraction of the gap closed per second.
+const MOOD_ADJUST_RATE: f32 = 0.05;
+// Seconds of uninterrupted work after which villagers start to feel overworked.
+const OVERWORK_AFTER: f32 = 60.0;
+// How much faster work strain goes away than it builds up.
+const REST_RATE: f32 = 2.0;
+// Number of friends after which more friends don't make villagers any happier.
+const ENOUGH_FRIENDS: f32 = 3.0;
+// Below this mood villagers may refuse the jobs they are offered.
+const REFUSAL_MOOD: f32 = 30.0;
+// Chance of refusing a job at a mood of 0.
+const MAX_REFUSAL_CHANCE: f32 = 0.3;
+// Seconds a villager who refused a job won't look for work.
+const REFUSAL_COOLDOWN: f32 = 20.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MoodModifier {
+    UnmetNeeds,
+    Comfort,
+    RecentEvents,
+    Overwork,
+    Relationships,
+}
+
+impl MoodModifier {
+    pub const ALL: [MoodModifier; 5] = [
+        MoodModifier::UnmetNeeds,
+        MoodModifier::Comfort,
+        MoodModifier::RecentEvents,
+        MoodModifier::Overwork,
+        MoodModifier::Relationships,
+    ];
+
+    /// How many points of mood the modifier is worth when fully positive or negative.
+    pub fn weight(&self) -> f32 {
+        match self {
+            MoodModifier::UnmetNeeds => 30.0,
+            MoodModifier::Comfort => 15.0,
+            MoodModifier::RecentEvents => 20.0,
+            MoodModifier::Overwork => 15.0,
+            MoodModifier::Relationships => 10.0,
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MoodEvent {
+    Ate,
+    SleptInBed,
+    SleptOutside,
+    FinishedJob,
+    Chatted,
+}
+
+impl MoodEvent {
+    /// Effect on the recent events modifier while the event is remembered.
+    pub fn effect(&self) -> f32 {
+        match self {
+            MoodEvent::Ate => 0.3,
+            MoodEvent::SleptInBed => 0.5,
+            MoodEvent::SleptOutside => -0.5,
+            MoodEvent::FinishedJob => 0.2,
+            MoodEvent::Chatted => 0.2,
+        }
+    }
+
+    /// Seconds the event is remembered for.
+    pub fn duration(&self) -> f32 {
+        match self {
+            MoodEvent::Ate => 60.0,
+            MoodEvent::SleptInBed | MoodEvent::SleptOutside => 120.0,
+            MoodEvent::FinishedJob => 30.0,
+            MoodEvent::Chatted => 45.0,
+        }
+    }
+}
+
+/// Sent whenever something happens to a villager that they will remember for a while.
+#[derive(Clone, Copy, Debug)]
+pub struct MoodEventOccurred {
+    pub villager: Entity,
+    pub event: MoodEvent,
+}
+
+#[derive(Component, Clone, Debug)]
+pub struct Mood {
+    //Current mood, from 0 when miserable to 100 when delighted
+    pub mood: f32,
+    //Value of every modifier, between -1 and 1
+    modifiers: [f32; 5],
+    //Events still remembered, with the seconds left until they're forgotten
+    memories: Vec<(MoodEvent, f32)>,
+    //Seconds of recent work, going down again while resting
+    work_strain: f32,
+}
+
+impl Default for Mood {
+    fn default() -> Self {
+        Self {
+            mood: BASE_MOOD,
+            modifiers: [0.0; 5],
+            memories: Vec::new(),
+            work_strain: 0.0,
+        }
+    }
+}
+
+impl Mood {
+    pub fn modifier(&self, modifier: MoodModifier) -> f32 {
+        self.modifiers[modifier.index()]
+    }
+
+    /// The mood the villager is drifting towards.
+    pub fn target(&self) -> f32 {
+        let offset: f32 = MoodModifier::ALL
+            .iter()
+            .map(|modifier| modifier.weight() * self.modifier(*modifier))
+            .sum();
+        (BASE_MOOD + offset).clamp(0.0, 100.0)
+    }
+
+    pub fn remember(&mut self, event: MoodEvent) {
+        self.memories.push((event, event.duration()));
+    }
+
+    /// Unhappy villagers work up to a quarter slower, happy ones up to a quarter faster.
+    pub fn work_multiplier(&self) -> f32 {
+        0.75 + 0.5 * self.mood / 100.0
+    }
+
+    /// Scales how eager the villager is to look for work, making unhappy villagers idle more.
+    pub fn motivation(&self) -> f32 {
+        f32::min(1.0, 0.5 + self.mood / 100.0)
+    }
+
+    /// Chance of refusing a job when offered one.
+    pub fn refusal_chance(&self) -> f32 {
+        if self.mood >= REFUSAL_MOOD {
+            0.0
+        } else {
+            MAX_REFUSAL_CHANCE * (1.0 - self.mood / REFUSAL_MOOD)
+        }
+    }
+}
+
+/// A villager who refused a job and won't look for another until they've sulked for a while.
+#[derive(Component, Clone, Debug)]
+pub struct RefusedWork {
+    cooldown: Timer,
+}
+
+impl Default for RefusedWork {
+    fn default() -> Self {
+        Self {
+            cooldown: Timer::from_seconds(REFUSAL_COOLDOWN, TimerMode::Once),
+        }
+    }
+}
+
+pub fn recover_from_refusals(mut commands: Commands, time: Res<Time>, mut refusals: Query<(Entity, &mut RefusedWork)>) {
+    for (villager, mut refused) in refusals.iter_mut() {
+        if refused.cooldown.tick(time.delta()).finished() {
+            commands.entity(villager).remove::<RefusedWork>();
+        }
+    }
+}
+
+pub fn remember_mood_events(mut events: EventReader<MoodEventOccurred>, mut moods: Query<&mut Mood>) {
+    for MoodEventOccurred { villager, event } in events.iter() {
+        if let Ok(mut mood) = moods.get_mut(*villager) {
+            mood.remember(*event);
+        }
+    }
+}
+
+pub fn handle_mood(
+    time: Res<Time>,
+    map: Res<Map>,
+    mut villagers: Query<(
+        &mut Mood,
+        &Transform,
+        &Thirst,
+        &Hunger,
+        &Energy,
+        Option<&Home>,
+        Option<&HasJob>,
+        Option<&Loneliness>,
+        Option<&Relationships>,
+    )>,
+) {
+    let delta = time.delta_seconds();
+    villagers.par_for_each_mut(
+        100,
+        |(mut mood, transform, thirst, hunger, energy, home, has_job, loneliness, relationships)| {
+            let worst_need = f32::max(f32::max(thirst.thirst, hunger.hunger), 100.0 - energy.energy) / 100.0;
+            let needs = 1.0 - 2.0 * worst_need;
+
+            let tile = world_xy_tile_xy(transform.translation.xy());
+            let mut surroundings = 0.0;
+            for x in tile.x.saturating_sub(1)..=u32::min(tile.x + 1, map.width - 1) {
+                for y in tile.y.saturating_sub(1)..=u32::min(tile.y + 1, map.height - 1) {
+                    if let Some(feature) = map.features[map.tile_xy_idx(x, y)] {
+                        surroundings += feature.comfort();
+                    }
+                }
+            }
+            let housing = if home.is_some() { 0.5 } else { -0.5 };
+            let comfort = housing + surroundings / 9.0;
+
+            for (_, remaining) in mood.memories.iter_mut() {
+                *remaining -= delta;
+            }
+            mood.memories.retain(|(_, remaining)| *remaining > 0.0);
+            let events: f32 = mood.memories.iter().map(|(event, _)| event.effect()).sum();
+
+            if has_job.is_some() {
+                mood.work_strain += delta;
+            } else {
+                mood.work_strain = f32::max(mood.work_strain - REST_RATE * delta, 0.0);
+            }
+            let overwork = -(mood.work_strain - OVERWORK_AFTER) / OVERWORK_AFTER;
+
+            let friends = relationships.map_or(0, |relationships| relationships.friends().count());
+            let company = f32::min(friends as f32 / ENOUGH_FRIENDS, 1.0)
+                - loneliness.map_or(0.0, |loneliness| loneliness.loneliness / 100.0);
+
+            for (modifier, value) in [
+                (MoodModifier::UnmetNeeds, needs),
+                (MoodModifier::Comfort, comfort),
+                (MoodModifier::RecentEvents, events),
+                (MoodModifier::Overwork, f32::min(overwork, 0.0)),
+                (MoodModifier::Relationships, company),
+            ] {
+                mood.modifiers[modifier.index()] = value.clamp(-1.0, 1.0);
+            }
+
+            let target = mood.target();
+            mood.mood += (target - mood.mood) * f32::min(1.0, MOOD_ADJUST_RATE * delta);
+        },
+    );
+}
+
+/// Small dot above a villager, going from red when they're miserable to green when they're happy.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MoodIndicator;
+
+fn mood_color(mood: f32) -> Color {
+    let mood = mood / 100.0;
+    Color::rgb(1.0 - mood, mood, 0.1)
+}
+
+pub fn add_mood_indicators(mut commands: Commands, villagers: Query<(Entity, &Mood), Added<Mood>>) {
+    for (villager, mood) in villagers.iter() {
+        commands.entity(villager).with_children(|parent| {
+            parent.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: mood_color(mood.mood),
+                        custom_size: Some(Vec2::splat(3.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0.0, 10.0, 0.1),
+                    ..default()
+                },
+                MoodIndicator,
+                Name::from("Mood Indicator"),
+            ));
+        });
+    }
+}
+
+pub fn update_mood_indicators(moods: Query<&Mood>, mut indicators: Query<(&Parent, &mut Sprite), With<MoodIndicator>>) {
+    for (parent, mut sprite) in indicators.iter_mut() {
+        if let Ok(mood) = moods.get(parent.get()) {
+            sprite.color = mood_color(mood.mood);
+        }
+    }
+}
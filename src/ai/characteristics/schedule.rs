@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, RngComponent};
+
+// Hours the schedule of a villager can be shifted earlier or later than the usual one.
+const MAX_SHIFT: f32 = 1.0;
+
+/// What a villager is supposed to be doing at some point of the day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Activity {
+    Work,
+    Leisure,
+    Sleep,
+}
+
+impl Activity {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Activity::Work => "Work",
+            Activity::Leisure => "Leisure",
+            Activity::Sleep => "Sleep",
+        }
+    }
+
+    /// How much villagers want to take on jobs, nobody works at night.
+    pub fn work_bias(&self) -> f32 {
+        match self {
+            Activity::Work => 1.0,
+            Activity::Leisure => 0.3,
+            Activity::Sleep => 0.0,
+        }
+    }
+
+    /// How much villagers want to go to bed, only the exhausted sleep during work hours.
+    pub fn sleep_bias(&self) -> f32 {
+        match self {
+            Activity::Work => 0.5,
+            Activity::Leisure => 1.0,
+            Activity::Sleep => 2.0,
+        }
+    }
+
+    /// How much villagers want to chat with others.
+    pub fn social_bias(&self) -> f32 {
+        match self {
+            Activity::Work => 0.5,
+            Activity::Leisure => 1.5,
+            Activity::Sleep => 0.2,
+        }
+    }
+}
+
+/// The hours at which a villager gets up, works and goes to bed.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DailySchedule {
+    pub wake_up: f32,
+    pub work_start: f32,
+    pub work_end: f32,
+    pub bedtime: f32,
+}
+
+impl Default for DailySchedule {
+    fn default() -> Self {
+        Self {
+            wake_up: 6.0,
+            work_start: 8.0,
+            work_end: 17.0,
+            bedtime: 22.0,
+        }
+    }
+}
+
+impl DailySchedule {
+    /// The usual schedule, shifted a little so not everyone drops their tools at the same moment.
+    pub fn random(rng: &mut RngComponent) -> Self {
+        let shift = (rng.f32() * 2.0 - 1.0) * MAX_SHIFT;
+        let schedule = Self::default();
+        Self {
+            wake_up: schedule.wake_up + shift,
+            work_start: schedule.work_start + shift,
+            work_end: schedule.work_end + shift,
+            bedtime: schedule.bedtime + shift,
+        }
+    }
+
+    pub fn activity(&self, hour: f32) -> Activity {
+        if hour < self.wake_up || hour >= self.bedtime {
+            Activity::Sleep
+        } else if hour >= self.work_start && hour < self.work_end {
+            Activity::Work
+        } else {
+            Activity::Leisure
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn activity_follows_the_day() {
+        let schedule = DailySchedule::default();
+        assert_eq!(schedule.activity(3.0), Activity::Sleep);
+        assert_eq!(schedule.activity(7.0), Activity::Leisure);
+        assert_eq!(schedule.activity(12.0), Activity::Work);
+        assert_eq!(schedule.activity(19.0), Activity::Leisure);
+        assert_eq!(schedule.activity(23.0), Activity::Sleep);
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, RngComponent};
+
+const MAX_LEVEL: u32 = 20;
+const STARTING_MAX_LEVEL: u32 = 5;
+const EXPERIENCE_PER_LEVEL: f32 = 50.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Skill {
+    Chopping,
+    Mining,
+    Building,
+    Clearing,
+    Farming,
+    Hunting,
+}
+
+impl Skill {
+    pub const ALL: [Skill; 6] = [
+        Skill::Chopping,
+        Skill::Mining,
+        Skill::Building,
+        Skill::Clearing,
+        Skill::Farming,
+        Skill::Hunting,
+    ];
+
+    fn index(&self) -> usize {
+        match self {
+            Skill::Chopping => 0,
+            Skill::Mining => 1,
+            Skill::Building => 2,
+            Skill::Clearing => 3,
+            Skill::Farming => 4,
+            Skill::Hunting => 5,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SkillLevel {
+    pub level: u32,
+    //Experience accumulated towards the next level
+    pub experience: f32,
+}
+
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Skills {
+    levels: [SkillLevel; 6],
+}
+
+impl Skills {
+    pub fn random(rng: &mut RngComponent) -> Self {
+        let mut skills = Self::default();
+        for skill in Skill::ALL {
+            skills.levels[skill.index()].level = rng.u32(0..=STARTING_MAX_LEVEL);
+        }
+        skills
+    }
+
+    pub fn level(&self, skill: Skill) -> u32 {
+        self.levels[skill.index()].level
+    }
+
+    /// How fast this villager works at a skill compared to an average villager.
+    pub fn work_multiplier(&self, skill: Skill) -> f32 {
+        0.5 + self.level(skill) as f32 * 0.1
+    }
+
+    pub fn gain_experience(&mut self, skill: Skill, experience: f32) {
+        let skill_level = &mut self.levels[skill.index()];
+        if skill_level.level >= MAX_LEVEL {
+            return;
+        }
+
+        skill_level.experience += experience;
+        while skill_level.level < MAX_LEVEL {
+            let needed = EXPERIENCE_PER_LEVEL * (skill_level.level + 1) as f32;
+            if skill_level.experience < needed {
+                break;
+            }
+            skill_level.experience -= needed;
+            skill_level.level += 1;
+        }
+    }
+}
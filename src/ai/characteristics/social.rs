@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+// Relationship score from which another villager counts as a friend.
+pub const FRIEND_THRESHOLD: f32 = 50.0;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Loneliness {
+    //Rate of loneliness accumulation
+    pub per_second: f32,
+    //Current loneliness, 100 when desperate for company
+    pub loneliness: f32,
+}
+
+impl Loneliness {
+    pub fn new(loneliness: f32, per_second: f32) -> Self {
+        Self { per_second, loneliness }
+    }
+}
+
+/// How well a villager gets along with the villagers they have talked to, from 0 to 100.
+#[derive(Component, Clone, Debug, Default)]
+pub struct Relationships {
+    scores: HashMap<Entity, f32>,
+}
+
+impl Relationships {
+    pub fn get(&self, other: Entity) -> f32 {
+        self.scores.get(&other).copied().unwrap_or_default()
+    }
+
+    pub fn improve(&mut self, other: Entity, amount: f32) {
+        let score = self.scores.entry(other).or_default();
+        *score = (*score + amount).clamp(0.0, 100.0);
+    }
+
+    pub fn forget(&mut self, other: Entity) {
+        self.scores.remove(&other);
+    }
+
+    pub fn friends(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.scores.keys().copied().filter(|other| self.is_friend(*other))
+    }
+
+    pub fn is_friend(&self, other: Entity) -> bool {
+        self.get(other) >= FRIEND_THRESHOLD
+    }
+}
+
+pub fn handle_loneliness(time: Res<Time>, mut lonelinesses: Query<&mut Loneliness>) {
+    lonelinesses.par_for_each_mut(100, |mut loneliness| {
+        loneliness.loneliness += loneliness.per_second * time.delta_seconds();
+
+        //Clamp to 100.0
+        loneliness.loneliness = f32::min(loneliness.loneliness, 100.0);
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn friendship_needs_enough_improvement() {
+        let mut relationships = Relationships::default();
+        let other = Entity::from_raw(1);
+
+        relationships.improve(other, 30.0);
+        assert!(!relationships.is_friend(other));
+        assert_eq!(relationships.friends().count(), 0);
+
+        relationships.improve(other, 80.0);
+        assert_eq!(relationships.get(other), 100.0);
+        assert_eq!(relationships.friends().collect::<Vec<_>>(), vec![other]);
+    }
+}
@@ -0,0 +1,186 @@
+use std::f32::consts::FRAC_PI_2;
+
+use bevy::{math::Vec3Swizzles, prelude::*};
+
+use crate::{
+    animation::SpriteAssets,
+    gui::Notification,
+    items::{Container, Inventory, ItemLocation, ItemQuery, Storage, TownInventory},
+    jobs::{
+        job_queue::{Job, JobQueue},
+        Jobs,
+    },
+    map::{components::WaterSourceCapacity, world_xy_tile_xy},
+};
+
+use super::{
+    actions::{components::HasJob, shared_hauling::release_materials},
+    characteristics::{Health, Relationships, Thirst},
+    wildlife::Hunted,
+};
+
+// How long a corpse lies around before it's gone, in seconds.
+const CORPSE_DECAY: f32 = 60.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CauseOfDeath {
+    Dehydration,
+    Starvation,
+}
+
+impl CauseOfDeath {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CauseOfDeath::Dehydration => "thirst",
+            CauseOfDeath::Starvation => "hunger",
+        }
+    }
+}
+
+/// Sent when a villager has died, right before they are despawned.
+#[derive(Clone, Copy, Debug)]
+pub struct VillagerDied {
+    pub villager: Entity,
+}
+
+/// What's left of a villager, fading away over time.
+#[derive(Component, Clone, Debug)]
+pub struct Corpse {
+    decay: Timer,
+}
+
+impl Default for Corpse {
+    fn default() -> Self {
+        Self {
+            decay: Timer::from_seconds(CORPSE_DECAY, TimerMode::Once),
+        }
+    }
+}
+
+pub fn handle_deaths(
+    mut commands: Commands,
+    sprite_assets: Res<SpriteAssets>,
+    mut job_queue: ResMut<JobQueue>,
+    mut town_inventory: ResMut<TownInventory>,
+    mut item_query: ItemQuery,
+    mut storages: Query<&mut Container, With<Storage>>,
+    mut capacities: Query<&mut WaterSourceCapacity>,
+    mut villagers: Query<(
+        Entity,
+        &Health,
+        &Thirst,
+        &Transform,
+        Option<&Name>,
+        Option<&HasJob>,
+        Option<&mut Inventory>,
+    )>,
+    mut deaths: EventWriter<VillagerDied>,
+    mut notifications: EventWriter<Notification>,
+) {
+    for (villager, health, thirst, transform, name, has_job, inventory) in villagers.iter_mut() {
+        if !health.is_dead() {
+            continue;
+        }
+
+        let cause = if thirst.thirst >= 100.0 {
+            CauseOfDeath::Dehydration
+        } else {
+            CauseOfDeath::Starvation
+        };
+        let name = name.map_or("A villager", |name| name.as_str());
+        let tile = world_xy_tile_xy(transform.translation.xy());
+
+        if let Some(has_job) = has_job {
+            release_job(
+                &mut commands,
+                &has_job.job,
+                inventory.as_deref(),
+                &mut job_queue,
+                &mut storages,
+                &mut town_inventory,
+            );
+        }
+        if let Some(mut inventory) = inventory {
+            //Everything they were carrying ends up on the ground, for someone else to haul.
+            for stack in inventory.drain() {
+                town_inventory.remove(stack.item, ItemLocation::InTransit, stack.count);
+                item_query.drop_item(stack, tile);
+            }
+        }
+        for mut capacity in capacities.iter_mut() {
+            capacity.release(villager);
+        }
+
+        commands.spawn((
+            SpriteSheetBundle {
+                sprite: TextureAtlasSprite {
+                    color: Color::GRAY,
+                    ..TextureAtlasSprite::new(0)
+                },
+                texture_atlas: sprite_assets.villager.clone(),
+                transform: Transform {
+                    translation: transform.translation.xy().extend(1.5),
+                    rotation: Quat::from_rotation_z(FRAC_PI_2),
+                    ..default()
+                },
+                ..default()
+            },
+            Corpse::default(),
+            Name::new(format!("Corpse of {}", name)),
+        ));
+        commands.entity(villager).despawn_recursive();
+
+        let message = format!("{} died of {}.", name, cause.name());
+        info!("{}", message);
+        notifications.send(Notification(message));
+        deaths.send(VillagerDied { villager });
+    }
+}
+
+/// Puts a dead villager's job back in the queue, freeing the materials that were set aside for it. Hunts are called
+/// off.
+fn release_job(
+    commands: &mut Commands,
+    job: &Job,
+    inventory: Option<&Inventory>,
+    job_queue: &mut JobQueue,
+    storages: &mut Query<&mut Container, With<Storage>>,
+    town_inventory: &mut TownInventory,
+) {
+    release_materials(job, inventory, job_queue, storages, town_inventory);
+
+    let picked_up = matches!(job.job_type, Jobs::Haul(_)) && inventory.map_or(false, |inventory| !inventory.is_empty());
+    if let Jobs::Hunt(prey) = job.job_type {
+        //The prey has long moved on, so the hunt is called off and the animal can be hunted again.
+        commands.entity(prey).remove::<Hunted>();
+        job_queue.complete(job.id);
+    } else if picked_up {
+        //What they carried is dropped and hauled under a new job.
+        job_queue.complete(job.id);
+    } else {
+        job_queue.requeue(*job);
+    }
+}
+
+pub fn forget_the_dead(mut deaths: EventReader<VillagerDied>, mut relationships: Query<&mut Relationships>) {
+    for death in deaths.iter() {
+        for mut villager_relationships in relationships.iter_mut() {
+            villager_relationships.forget(death.villager);
+        }
+    }
+}
+
+pub fn decay_corpses(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut corpses: Query<(Entity, &mut Corpse, &mut TextureAtlasSprite)>,
+) {
+    for (entity, mut corpse, mut sprite) in corpses.iter_mut() {
+        corpse.decay.tick(time.delta());
+        sprite.color.set_a(corpse.decay.percent_left());
+
+        if corpse.decay.finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::simulation::WorldClock;
+
+use super::actions::components::ActionLabel;
+
+// How many of their latest doings a villager's history keeps.
+const HISTORY_LENGTH: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct HistoryEntry {
+    pub day: u32,
+    pub hour: f32,
+    pub text: String,
+}
+
+/// What a villager has been up to lately, newest last.
+#[derive(Component, Clone, Debug, Default)]
+pub struct History {
+    entries: VecDeque<HistoryEntry>,
+}
+
+impl History {
+    pub fn record(&mut self, clock: &WorldClock, text: String) {
+        if self.entries.back().map_or(false, |last| last.text == text) {
+            //Still doing the same thing.
+            return;
+        }
+
+        self.entries.push_back(HistoryEntry {
+            day: clock.day(),
+            hour: clock.hour(),
+            text,
+        });
+        while self.entries.len() > HISTORY_LENGTH {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
+        self.entries.iter()
+    }
+}
+
+pub fn record_actions(
+    clock: Res<WorldClock>,
+    actions: Query<(&Actor, &ActionState, &ActionLabel), Changed<ActionState>>,
+    mut histories: Query<&mut History>,
+) {
+    for (Actor(actor), action_state, ActionLabel(label)) in actions.iter() {
+        let text = match action_state {
+            ActionState::Executing => label.to_string(),
+            ActionState::Failure => format!("{} (failed)", label),
+            _ => continue,
+        };
+        if let Ok(mut history) = histories.get_mut(*actor) {
+            history.record(&clock, text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn history_is_bounded_and_skips_repeats() {
+        let clock = WorldClock::default();
+        let mut history = History::default();
+        history.record(&clock, "Walking".to_string());
+        history.record(&clock, "Walking".to_string());
+        assert_eq!(history.entries().count(), 1);
+
+        for i in 0..HISTORY_LENGTH * 2 {
+            history.record(&clock, format!("Entry {}", i));
+        }
+        assert_eq!(history.entries().count(), HISTORY_LENGTH);
+        assert_eq!(
+            history.entries().last().map(|entry| entry.text.as_str()),
+            Some(format!("Entry {}", HISTORY_LENGTH * 2 - 1).as_str())
+        );
+    }
+}
@@ -5,22 +5,39 @@ use iyes_progress::ProgressSystem;
 
 use crate::states::GameStates;
 
-mod actions;
-mod characteristics;
+pub use behaviours::{Archetype, BehaviourAssets};
+pub use population::Population;
+
+pub mod actions;
+mod behaviours;
+pub mod characteristics;
+mod death;
+pub mod history;
 mod pickers;
+mod population;
 mod scorers;
 mod spawner;
+pub mod trace;
+pub mod wildlife;
 
 pub struct AIPlugin;
 
 impl Plugin for AIPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(BigBrainPlugin);
+        app.add_plugin(BigBrainPlugin)
+            .add_asset::<behaviours::BehaviourDefinition>()
+            .init_asset_loader::<behaviours::BehaviourLoader>()
+            .add_event::<characteristics::MoodEventOccurred>()
+            .add_event::<death::VillagerDied>()
+            .add_event::<trace::ExportTraces>()
+            .init_resource::<Population>()
+            .init_resource::<trace::TraceRecorder>();
 
         app.add_system_set(
             ConditionSet::new()
                 .run_in_state(GameStates::InGamePrepare)
                 .with_system(spawner::spawn_ai.track_progress())
+                .with_system(wildlife::spawn_wildlife.track_progress())
                 .into(),
         );
 
@@ -28,6 +45,27 @@ impl Plugin for AIPlugin {
             ConditionSet::new()
                 .run_in_state(GameStates::InGame)
                 .with_system(characteristics::thirst::handle_thirst)
+                .with_system(characteristics::hunger::handle_hunger)
+                .with_system(characteristics::energy::handle_energy)
+                .with_system(characteristics::social::handle_loneliness)
+                .with_system(characteristics::health::handle_health)
+                .with_system(characteristics::home::find_bed_rooms)
+                .with_system(characteristics::home::assign_homes)
+                .with_system(characteristics::mood::handle_mood)
+                .with_system(characteristics::mood::remember_mood_events)
+                .with_system(characteristics::mood::recover_from_refusals)
+                .with_system(characteristics::mood::add_mood_indicators)
+                .with_system(characteristics::mood::update_mood_indicators)
+                .with_system(death::handle_deaths)
+                .with_system(death::forget_the_dead)
+                .with_system(death::decay_corpses)
+                .with_system(population::grow_population)
+                .with_system(history::record_actions)
+                .with_system(trace::start_tracing)
+                .with_system(trace::trace_scores)
+                .with_system(trace::trace_picks)
+                .with_system(trace::trace_action_states)
+                .with_system(trace::export_traces)
                 .into(),
         );
 
@@ -42,6 +80,18 @@ impl Plugin for AIPlugin {
                 .with_system(actions::move_to_destination::move_to_destination)
                 .with_system(actions::drink::drink)
                 .with_system(actions::do_job::do_job)
+                .with_system(actions::pick_up_item::pick_up_item)
+                .with_system(actions::storage_destination::storage_destination)
+                .with_system(actions::deliver_item::deliver_item)
+                .with_system(actions::materials_destination::materials_destination)
+                .with_system(actions::pick_up_materials::pick_up_materials)
+                .with_system(actions::food_destination::food_destination)
+                .with_system(actions::eat::eat)
+                .with_system(actions::bed_destination::bed_destination)
+                .with_system(actions::sleep::sleep)
+                .with_system(actions::peer_destination::peer_destination)
+                .with_system(actions::chat::chat)
+                .with_system(actions::flee_destination::flee_destination)
                 .into(),
         );
         app.add_system_set_to_stage(
@@ -49,7 +99,11 @@ impl Plugin for AIPlugin {
             ConditionSet::new()
                 .run_in_state(GameStates::InGame)
                 .with_system(scorers::thirsty::thirsty_scorer)
+                .with_system(scorers::hungry::hungry_scorer)
+                .with_system(scorers::tired::tired_scorer)
                 .with_system(scorers::job_available::job_available_scorer)
+                .with_system(scorers::lonely::lonely_scorer)
+                .with_system(scorers::threatened::threatened_scorer)
                 .into(),
         );
     }
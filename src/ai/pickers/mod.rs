@@ -1 +1,3 @@
 pub mod highest_score;
+pub mod sticky;
+pub mod weighted_random;
@@ -0,0 +1,66 @@
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use big_brain::{choices::Choice, prelude::*};
+
+/// Picks the highest scoring choice, but keeps the previous pick until another choice beats it by `margin`.
+#[derive(Debug)]
+pub struct Sticky {
+    margin: f32,
+    //Index of the choice picked last time
+    current: Mutex<Option<usize>>,
+}
+
+impl Sticky {
+    pub fn new(margin: f32) -> Self {
+        Self {
+            margin,
+            current: Mutex::new(None),
+        }
+    }
+}
+
+impl Sticky {
+    fn pick_index(&self, scores: &[f32]) -> Option<usize> {
+        let (best, best_score) = scores
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())?;
+
+        let mut current = self.current.lock().unwrap();
+        let keep = current
+            .filter(|idx| *idx < scores.len())
+            .filter(|idx| scores[*idx] + self.margin >= best_score);
+        let picked = keep.unwrap_or(best);
+        *current = Some(picked);
+        Some(picked)
+    }
+}
+
+impl Picker for Sticky {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        let scores: Vec<_> = choices.iter().map(|choice| choice.calculate(scores)).collect();
+        self.pick_index(&scores).map(|idx| &choices[idx])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn first_pick_is_the_best() {
+        let picker = Sticky::new(0.1);
+        assert_eq!(picker.pick_index(&[0.2, 0.6, 0.4]), Some(1));
+        assert_eq!(picker.pick_index(&[]), None);
+    }
+
+    #[test]
+    fn pick_sticks_within_margin() {
+        let picker = Sticky::new(0.1);
+        assert_eq!(picker.pick_index(&[0.5, 0.4]), Some(0));
+        assert_eq!(picker.pick_index(&[0.5, 0.55]), Some(0));
+        assert_eq!(picker.pick_index(&[0.5, 0.65]), Some(1));
+    }
+}
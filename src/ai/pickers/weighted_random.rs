@@ -0,0 +1,118 @@
+use std::sync::Mutex;
+
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, RngComponent};
+use big_brain::{choices::Choice, prelude::*};
+
+#[derive(Debug)]
+struct RollState {
+    //Forked from the villager's own generator, so every villager rolls differently
+    rng: RngComponent,
+    //Position of the roll among the summed scores, between 0 and 1
+    roll: f32,
+    //Index of the choice picked last time
+    current: Option<usize>,
+}
+
+/// Picks a choice at random, with a chance proportional to its score.
+///
+/// The roll is kept until the picked choice scores below `threshold`, so slowly changing scores don't make the
+/// villager switch back and forth every frame. Choices scoring below `threshold` are never picked.
+#[derive(Debug)]
+pub struct WeightedRandom {
+    threshold: f32,
+    state: Mutex<RollState>,
+}
+
+impl WeightedRandom {
+    pub fn new(threshold: f32, rng: &mut RngComponent) -> Self {
+        let mut rng = RngComponent::from(rng);
+        let roll = rng.f32();
+        Self {
+            threshold,
+            state: Mutex::new(RollState {
+                rng,
+                roll,
+                current: None,
+            }),
+        }
+    }
+}
+
+impl WeightedRandom {
+    /// Index of the picked score, `None` when every score is below the threshold.
+    fn pick_index(&self, scores: &[f32]) -> Option<usize> {
+        let weights: Vec<_> = scores
+            .iter()
+            .map(|score| if *score >= self.threshold { score.max(0.0) } else { 0.0 })
+            .collect();
+        let total: f32 = weights.iter().sum();
+        if total <= 0.0 {
+            //Leave it to the thinker's fallback, if it has one.
+            return None;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        if let Some(current) = state.current {
+            if weights.get(current).map_or(true, |weight| *weight <= 0.0) {
+                state.roll = state.rng.f32();
+            }
+        }
+
+        let mut remaining = state.roll * total;
+        let picked = weights
+            .iter()
+            .position(|weight| {
+                if *weight > 0.0 && remaining < *weight {
+                    return true;
+                }
+                remaining -= weight;
+                false
+            })
+            .or_else(|| weights.iter().rposition(|weight| *weight > 0.0));
+        state.current = picked;
+        picked
+    }
+}
+
+impl Picker for WeightedRandom {
+    fn pick<'a>(&self, choices: &'a [Choice], scores: &Query<&Score>) -> Option<&'a Choice> {
+        let scores: Vec<_> = choices.iter().map(|choice| choice.calculate(scores)).collect();
+        self.pick_index(&scores).map(|idx| &choices[idx])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn picker(threshold: f32) -> WeightedRandom {
+        WeightedRandom::new(threshold, &mut RngComponent::with_seed(7))
+    }
+
+    #[test]
+    fn nothing_is_picked_below_threshold() {
+        let picker = picker(0.3);
+        assert_eq!(picker.pick_index(&[0.1, 0.2, 0.0]), None);
+        assert_eq!(picker.pick_index(&[]), None);
+    }
+
+    #[test]
+    fn only_choices_above_threshold_are_picked() {
+        let picker = picker(0.3);
+        for _ in 0..20 {
+            assert_eq!(picker.pick_index(&[0.2, 0.9, 0.1]), Some(1));
+        }
+    }
+
+    #[test]
+    fn roll_is_kept_while_the_pick_scores() {
+        let picker = picker(0.3);
+        let first = picker
+            .pick_index(&[0.5, 0.5, 0.5])
+            .expect("Something should be picked.");
+        for _ in 0..20 {
+            assert_eq!(picker.pick_index(&[0.5, 0.5, 0.5]), Some(first));
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use bevy_turborand::{DelegatedRng, GlobalRng, RngComponent};
+
+use crate::{
+    animation::SpriteAssets,
+    gui::Notification,
+    items::{Items, TownInventory},
+    map::{
+        components::{Bed, WaterSource, WaterSourceCapacity},
+        tile_xy_world_xy, Map, MapPathfinding,
+    },
+    tech::Research,
+};
+
+use super::{
+    behaviours::{BehaviourAssets, BehaviourDefinition},
+    characteristics::{Home, Mood, Relationships, FRIEND_THRESHOLD},
+    spawner::spawn_villager,
+};
+
+// How often, in seconds, the town is checked for newcomers.
+const GROWTH_INTERVAL: f32 = 30.0;
+// Average mood the town needs before anyone wants to move in.
+const IMMIGRATION_MOOD: f32 = 50.0;
+// Most immigrants arriving at once.
+const MAX_IMMIGRANTS: u32 = 3;
+// Tries at finding a spot on the map edge from which the town can be reached.
+const EDGE_ATTEMPTS: u32 = 20;
+// Chance for a household to have a child at every growth check.
+const BIRTH_CHANCE: f64 = 0.1;
+// How close, in tiles, two villagers' beds have to be for them to share a household.
+const HOUSEHOLD_DISTANCE: u32 = 8;
+// Villagers that can comfortably share a single drinking spot.
+const VILLAGERS_PER_DRINKING_SPOT: u32 = 5;
+// Stored nutrition the town should have for every villager it feeds.
+const NUTRITION_PER_VILLAGER: f32 = 20.0;
+
+#[derive(Resource, Clone, Debug)]
+pub struct Population {
+    //Number given to the next villager, also how many have ever lived in town
+    next_id: u32,
+    //How many villagers the town can house, water and feed
+    capacity: u32,
+    timer: Timer,
+}
+
+impl Default for Population {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            capacity: 0,
+            timer: Timer::from_seconds(GROWTH_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl Population {
+    pub fn next_name(&mut self) -> String {
+        let name = format!("Villager {}", self.next_id);
+        self.next_id += 1;
+        name
+    }
+
+    pub fn spawned(&self) -> u32 {
+        self.next_id
+    }
+
+    pub fn capacity(&self) -> u32 {
+        self.capacity
+    }
+}
+
+/// How many villagers the town can support, limited by whichever of housing, water and food runs out first.
+fn town_capacity(beds: u32, drinking_spots: u32, stored_nutrition: f32) -> u32 {
+    let water = drinking_spots.saturating_mul(VILLAGERS_PER_DRINKING_SPOT);
+    let food = (stored_nutrition / NUTRITION_PER_VILLAGER) as u32;
+    beds.min(water).min(food)
+}
+
+pub fn grow_population(
+    mut commands: Commands,
+    time: Res<Time>,
+    map: Res<Map>,
+    map_pathfinding: Res<MapPathfinding>,
+    town_inventory: Res<TownInventory>,
+    sprite_assets: Res<SpriteAssets>,
+    behaviour_assets: Res<BehaviourAssets>,
+    behaviours: Res<Assets<BehaviourDefinition>>,
+    research: Res<Research>,
+    mut rng: ResMut<GlobalRng>,
+    mut population: ResMut<Population>,
+    beds: Query<&Bed>,
+    water_sources: Query<Option<&WaterSourceCapacity>, With<WaterSource>>,
+    villagers: Query<&Mood>,
+    households: Query<(Entity, &Home, &Relationships, &Name)>,
+    mut notifications: EventWriter<Notification>,
+) {
+    //Only tick when it's time, so the resource isn't marked as changed every frame.
+    if !population
+        .bypass_change_detection()
+        .timer
+        .tick(time.delta())
+        .just_finished()
+    {
+        return;
+    }
+
+    let drinking_spots = water_sources
+        .iter()
+        .map(|capacity| capacity.map_or(1, |capacity| capacity.max_drinkers as u32))
+        .sum();
+    let stored_nutrition: f32 = Items::ALL
+        .into_iter()
+        .map(|item| town_inventory.counts(item).stored as f32 * item.nutrition())
+        .sum();
+    //Only beds that can be assigned as homes count towards housing.
+    let home_beds = beds.iter().filter(|bed| bed.in_room).count() as u32;
+    population.capacity = town_capacity(home_beds, drinking_spots, stored_nutrition);
+
+    let size = villagers.iter().count() as u32;
+    let mut room = population.capacity.saturating_sub(size);
+    if room == 0 {
+        return;
+    }
+
+    //Households are friends sleeping close to each other, every one of them might have a child.
+    let homes: HashMap<Entity, TilePos> = households
+        .iter()
+        .map(|(villager, home, _, _)| (villager, home.position))
+        .collect();
+    for (villager, home, relationships, name) in households.iter() {
+        let partner = relationships
+            .friends()
+            .filter(|friend| *friend > villager)
+            .filter(|friend| {
+                homes.get(friend).map_or(false, |friend_home| {
+                    friend_home.x.abs_diff(home.position.x) <= HOUSEHOLD_DISTANCE
+                        && friend_home.y.abs_diff(home.position.y) <= HOUSEHOLD_DISTANCE
+                })
+            })
+            .find_map(|friend| households.get(friend).ok());
+        let (partner, partner_name) = match partner {
+            Some((partner, _, _, partner_name)) => (partner, partner_name),
+            None => continue,
+        };
+        if room == 0 || !rng.chance(BIRTH_CHANCE) {
+            continue;
+        }
+
+        let child_name = population.next_name();
+        let child = match spawn_villager(
+            &mut commands,
+            &sprite_assets,
+            &behaviour_assets,
+            &behaviours,
+            &research,
+            RngComponent::from(&mut rng),
+            tile_xy_world_xy(home.position.x, home.position.y),
+            child_name.clone(),
+        ) {
+            Some(child) => child,
+            None => return,
+        };
+        //Children know their parents from the start.
+        let mut child_relationships = Relationships::default();
+        child_relationships.improve(villager, FRIEND_THRESHOLD);
+        child_relationships.improve(partner, FRIEND_THRESHOLD);
+        commands.entity(child).insert(child_relationships);
+        notifications.send(Notification(format!(
+            "{} was born to {} and {}.",
+            child_name,
+            name.as_str(),
+            partner_name.as_str()
+        )));
+        room -= 1;
+    }
+
+    let average_mood = villagers.iter().map(|mood| mood.mood).sum::<f32>() / size.max(1) as f32;
+    if room == 0 || average_mood < IMMIGRATION_MOOD {
+        return;
+    }
+
+    let town_center = TilePos::new(map.width / 2, map.height / 2);
+    let arrival = match edge_tile(&map, &map_pathfinding, town_center, &mut rng) {
+        Some(arrival) => arrival,
+        None => return,
+    };
+    let immigrants = u32::min(rng.u32(1..=MAX_IMMIGRANTS), room);
+    for _ in 0..immigrants {
+        let name = population.next_name();
+        let arrived = spawn_villager(
+            &mut commands,
+            &sprite_assets,
+            &behaviour_assets,
+            &behaviours,
+            &research,
+            RngComponent::from(&mut rng),
+            tile_xy_world_xy(arrival.x, arrival.y),
+            name,
+        );
+        if arrived.is_none() {
+            return;
+        }
+    }
+    notifications.send(Notification(if immigrants == 1 {
+        "An immigrant arrived in town.".to_string()
+    } else {
+        format!("{} immigrants arrived in town.", immigrants)
+    }));
+}
+
+/// Picks a passable tile on the edge of the map from which newcomers can walk into town.
+fn edge_tile(
+    map: &Map,
+    map_pathfinding: &MapPathfinding,
+    town_center: TilePos,
+    rng: &mut GlobalRng,
+) -> Option<TilePos> {
+    (0..EDGE_ATTEMPTS).find_map(|_| {
+        let tile = match rng.u32(0..4) {
+            0 => TilePos::new(rng.u32(0..map.width), 0),
+            1 => TilePos::new(rng.u32(0..map.width), map.height - 1),
+            2 => TilePos::new(0, rng.u32(0..map.height)),
+            _ => TilePos::new(map.width - 1, rng.u32(0..map.height)),
+        };
+        (map.is_passable(tile.x, tile.y) && map_pathfinding.get_path(map, tile, town_center).is_some()).then_some(tile)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn capacity_is_limited_by_scarcest_supply() {
+        assert_eq!(town_capacity(10, 4, 1000.0), 10);
+        assert_eq!(town_capacity(10, 1, 1000.0), VILLAGERS_PER_DRINKING_SPOT);
+        assert_eq!(town_capacity(10, 4, 3.0 * NUTRITION_PER_VILLAGER), 3);
+        assert_eq!(town_capacity(0, 4, 1000.0), 0);
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::*;
+
+/// What a scorer is called when showing villagers' decisions to the player.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScorerLabel(pub &'static str);
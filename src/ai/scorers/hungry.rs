@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::ai::characteristics::hunger::Hunger;
+
+use super::response_curve::{curved_score, ResponseCurve};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Hungry;
+
+pub fn hungry_scorer(
+    hungers: Query<&Hunger>,
+    mut actors: Query<(&Actor, &mut Score, Option<&mut ResponseCurve>), With<Hungry>>,
+) {
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, curve)| {
+        if let Ok(hunger) = hungers.get(*actor) {
+            score.set(curved_score(curve, hunger.hunger / 100.0));
+        }
+    });
+}
@@ -2,22 +2,55 @@ use bevy::prelude::*;
 use big_brain::prelude::*;
 
 use crate::{
-    ai::{actions::components::HasJob, characteristics::job_seeker::*},
-    jobs::job_queue::JobQueue,
+    ai::{
+        actions::components::HasJob,
+        characteristics::{job_seeker::*, DailySchedule, Mood, RefusedWork},
+    },
+    jobs::{job_queue::JobQueue, JobCategory},
+    simulation::WorldClock,
 };
 
 #[derive(Component, Clone, Copy, Debug)]
-pub struct JobAvailable;
+pub struct JobAvailable {
+    pub category: JobCategory,
+}
+
+impl JobAvailable {
+    pub fn new(category: JobCategory) -> Self {
+        Self { category }
+    }
+
+    fn score(&self) -> f32 {
+        match self.category {
+            JobCategory::Work => 0.8,
+            JobCategory::Haul => 0.7,
+        }
+    }
+}
 
 pub fn job_available_scorer(
     job_queue: Res<JobQueue>,
-    job_seekers: Query<(&JobSeeker, Option<&HasJob>)>,
-    mut actors: Query<(&Actor, &mut Score), With<JobAvailable>>,
+    clock: Res<WorldClock>,
+    job_seekers: Query<(
+        &JobSeeker,
+        Option<&HasJob>,
+        Option<&Mood>,
+        Option<&DailySchedule>,
+        Option<&RefusedWork>,
+    )>,
+    mut actors: Query<(&Actor, &mut Score, &JobAvailable)>,
 ) {
-    actors.par_for_each_mut(100, |(Actor(actor), mut score)| {
-        if let Ok((_job_seeker, has_job)) = job_seekers.get(*actor) {
-            if has_job.is_some() || job_queue.jobs.front().is_some() {
-                score.set(0.8);
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, job_available)| {
+        if let Ok((_job_seeker, has_job, mood, schedule, refused)) = job_seekers.get(*actor) {
+            let available = if let Some(has_job) = has_job {
+                has_job.job.job_type.category() == job_available.category
+            } else {
+                refused.is_none() && job_queue.has_available(job_available.category)
+            };
+
+            if available {
+                let bias = schedule.map_or(1.0, |schedule| schedule.activity(clock.hour()).work_bias());
+                score.set(job_available.score() * mood.map_or(1.0, |mood| mood.motivation()) * bias);
             } else {
                 score.set(0.0);
             }
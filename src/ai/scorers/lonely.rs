@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::{
+    ai::{
+        actions::components::HasJob,
+        characteristics::{social::Loneliness, DailySchedule},
+    },
+    simulation::WorldClock,
+};
+
+use super::response_curve::{curved_score, ResponseCurve};
+
+/// Scores idle villagers by how much they want company.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Lonely;
+
+pub fn lonely_scorer(
+    clock: Res<WorldClock>,
+    lonelinesses: Query<(&Loneliness, Option<&HasJob>, Option<&DailySchedule>)>,
+    mut actors: Query<(&Actor, &mut Score, Option<&mut ResponseCurve>), With<Lonely>>,
+) {
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, curve)| {
+        if let Ok((loneliness, has_job, schedule)) = lonelinesses.get(*actor) {
+            if has_job.is_some() {
+                score.set(0.0);
+            } else {
+                let bias = schedule.map_or(1.0, |schedule| schedule.activity(clock.hour()).social_bias());
+                score.set(f32::min(curved_score(curve, loneliness.loneliness / 100.0) * bias, 1.0));
+            }
+        }
+    });
+}
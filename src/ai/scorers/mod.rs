@@ -1,2 +1,8 @@
+pub mod components;
+pub mod hungry;
 pub mod job_available;
+pub mod lonely;
+pub mod response_curve;
 pub mod thirsty;
+pub mod threatened;
+pub mod tired;
@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+
+/// Shapes an input between 0 and 1 into a score between 0 and 1.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+pub enum Curve {
+    #[default]
+    Linear,
+    //Stays low for most of the range and rises sharply near the end when `exponent` is above 1
+    Exponential {
+        exponent: f32,
+    },
+    //S-shaped, crossing 0.5 at `midpoint`
+    Logistic {
+        midpoint: f32,
+        steepness: f32,
+    },
+    //0 below `threshold`, 1 from it onwards
+    Step {
+        threshold: f32,
+    },
+    Inverted(Box<Curve>),
+    //Feeds the output of each curve into the next one
+    Chain(Vec<Curve>),
+}
+
+impl Curve {
+    pub fn evaluate(&self, input: f32) -> f32 {
+        let input = input.clamp(0.0, 1.0);
+        let output = match self {
+            Curve::Linear => input,
+            Curve::Exponential { exponent } => input.powf(*exponent),
+            Curve::Logistic { midpoint, steepness } => 1.0 / (1.0 + (-steepness * (input - midpoint)).exp()),
+            Curve::Step { threshold } => {
+                if input >= *threshold {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            Curve::Inverted(curve) => 1.0 - curve.evaluate(input),
+            Curve::Chain(curves) => curves.iter().fold(input, |value, curve| curve.evaluate(value)),
+        };
+        output.clamp(0.0, 1.0)
+    }
+}
+
+/// Keeps a scorer quiet until its input reaches `on`, then keeps it going until the input drops below `off`.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub struct Hysteresis {
+    pub on: f32,
+    pub off: f32,
+}
+
+/// Response curve of a scorer entity, remembering whether its hysteresis is currently switched on.
+#[derive(Component, Clone, Debug, Default, PartialEq, Deserialize)]
+pub struct ResponseCurve {
+    pub curve: Curve,
+    #[serde(default)]
+    pub hysteresis: Option<Hysteresis>,
+    #[serde(skip)]
+    active: bool,
+}
+
+impl ResponseCurve {
+    pub fn evaluate(&mut self, input: f32) -> f32 {
+        if let Some(hysteresis) = self.hysteresis {
+            if self.active && input < hysteresis.off {
+                self.active = false;
+            } else if !self.active && input >= hysteresis.on {
+                self.active = true;
+            }
+
+            if !self.active {
+                return 0.0;
+            }
+        }
+        self.curve.evaluate(input)
+    }
+}
+
+/// Score for a need between 0 and 1, shaped by the scorer's response curve if it has one.
+pub fn curved_score(curve: Option<Mut<ResponseCurve>>, input: f32) -> f32 {
+    match curve {
+        Some(mut curve) => curve.evaluate(input),
+        None => input.clamp(0.0, 1.0),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn curves_stay_in_range() {
+        let curves = [
+            Curve::Linear,
+            Curve::Exponential { exponent: 3.0 },
+            Curve::Logistic {
+                midpoint: 0.5,
+                steepness: 10.0,
+            },
+            Curve::Step { threshold: 0.7 },
+            Curve::Inverted(Box::new(Curve::Linear)),
+        ];
+        for curve in curves {
+            for input in [-1.0, 0.0, 0.3, 1.0, 2.0] {
+                let output = curve.evaluate(input);
+                assert!(
+                    (0.0..=1.0).contains(&output),
+                    "{:?} gave {} for {}",
+                    curve,
+                    output,
+                    input
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn chain_applies_curves_in_order() {
+        let chain = Curve::Chain(vec![
+            Curve::Inverted(Box::new(Curve::Linear)),
+            Curve::Step { threshold: 0.5 },
+        ]);
+        assert_eq!(chain.evaluate(0.2), 1.0);
+        assert_eq!(chain.evaluate(0.8), 0.0);
+    }
+
+    #[test]
+    fn hysteresis_holds_until_input_drops() {
+        let mut curve = ResponseCurve {
+            hysteresis: Some(Hysteresis { on: 0.6, off: 0.3 }),
+            ..default()
+        };
+        assert_eq!(curve.evaluate(0.5), 0.0);
+        assert_eq!(curve.evaluate(0.6), 0.6);
+        assert_eq!(curve.evaluate(0.4), 0.4);
+        assert_eq!(curve.evaluate(0.2), 0.0);
+        assert_eq!(curve.evaluate(0.5), 0.0);
+    }
+}
@@ -3,13 +3,18 @@ use big_brain::prelude::*;
 
 use crate::ai::characteristics::thirst::Thirst;
 
+use super::response_curve::{curved_score, ResponseCurve};
+
 #[derive(Component, Clone, Copy, Debug)]
 pub struct Thirsty;
 
-pub fn thirsty_scorer(thirsts: Query<&Thirst>, mut actors: Query<(&Actor, &mut Score), With<Thirsty>>) {
-    actors.par_for_each_mut(100, |(Actor(actor), mut score)| {
+pub fn thirsty_scorer(
+    thirsts: Query<&Thirst>,
+    mut actors: Query<(&Actor, &mut Score, Option<&mut ResponseCurve>), With<Thirsty>>,
+) {
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, curve)| {
         if let Ok(thirst) = thirsts.get(*actor) {
-            score.set(thirst.thirst / 100.0);
+            score.set(curved_score(curve, thirst.thirst / 100.0));
         }
     });
 }
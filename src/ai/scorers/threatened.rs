@@ -0,0 +1,36 @@
+use bevy::{math::Vec3Swizzles, prelude::*};
+use big_brain::prelude::*;
+
+use crate::{ai::characteristics::JobSeeker, map::TILE_SIZE};
+
+use super::response_curve::{curved_score, ResponseCurve};
+
+/// Scores animals by how close the nearest villager is, from 0 at `radius` tiles away to 1 right next to them.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Threatened {
+    pub radius: f32,
+}
+
+impl Threatened {
+    pub fn new(radius: f32) -> Self {
+        Self { radius }
+    }
+}
+
+pub fn threatened_scorer(
+    positions: Query<&Transform>,
+    villagers: Query<&Transform, With<JobSeeker>>,
+    mut actors: Query<(&Actor, &mut Score, &Threatened, Option<&mut ResponseCurve>)>,
+) {
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, threatened, curve)| {
+        if let Ok(actor_transform) = positions.get(*actor) {
+            let actor_position = actor_transform.translation.xy();
+            let nearest = villagers
+                .iter()
+                .map(|transform| transform.translation.xy().distance(actor_position) / TILE_SIZE.x)
+                .fold(f32::INFINITY, f32::min);
+            let threat = (1.0 - nearest / threatened.radius).clamp(0.0, 1.0);
+            score.set(curved_score(curve, threat));
+        }
+    });
+}
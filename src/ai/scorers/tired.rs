@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use big_brain::prelude::*;
+
+use crate::{
+    ai::characteristics::{energy::Energy, home::Home, DailySchedule},
+    simulation::WorldClock,
+};
+
+use super::response_curve::{curved_score, ResponseCurve};
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Tired {
+    //Only scores villagers that have (or don't have) a home to sleep in
+    pub has_home: bool,
+}
+
+impl Tired {
+    pub fn new(has_home: bool) -> Self {
+        Self { has_home }
+    }
+}
+
+pub fn tired_scorer(
+    clock: Res<WorldClock>,
+    energies: Query<(&Energy, Option<&Home>, Option<&DailySchedule>)>,
+    mut actors: Query<(&Actor, &mut Score, &Tired, Option<&mut ResponseCurve>)>,
+) {
+    actors.par_for_each_mut(100, |(Actor(actor), mut score, tired, curve)| {
+        if let Ok((energy, home, schedule)) = energies.get(*actor) {
+            if home.is_some() == tired.has_home {
+                let bias = schedule.map_or(1.0, |schedule| schedule.activity(clock.hour()).sleep_bias());
+                score.set(f32::min(curved_score(curve, 1.0 - energy.energy / 100.0) * bias, 1.0));
+            } else {
+                score.set(0.0);
+            }
+        }
+    });
+}
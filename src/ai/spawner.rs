@@ -1,82 +1,112 @@
 use bevy::prelude::*;
 use bevy_turborand::{GlobalRng, RngComponent};
-use big_brain::prelude::*;
 use iyes_progress::Progress;
 
 use crate::{
     animation::{AnimationTimer, SpriteAssets},
+    items::Inventory,
     map::{Map, TILE_SIZE},
+    tech::Research,
     SIMULATION_SPEED,
 };
 
-const NUM_AI: u32 = 1000;
+const STARTING_VILLAGERS: u32 = 8;
+const MAX_CARRY_WEIGHT: f32 = 25.0;
 
 use super::{
-    actions::{
-        do_job::DoJob, drink::Drink, job_destination::JobDestination, move_to_destination::MoveToDestination,
-        random_destination::RandomDestinationBuilder, take_job::TakingJob,
-        water_source_destination::WaterSourceDestination,
-    },
+    behaviours::{Archetype, BehaviourAssets, BehaviourDefinition},
     characteristics::*,
-    pickers::highest_score::HighestScore,
-    scorers::{job_available::JobAvailable, thirsty::Thirsty},
+    history::History,
+    population::Population,
 };
 
 pub fn spawn_ai(
     mut commands: Commands,
     map: Res<Map>,
     sprite_assets: Res<SpriteAssets>,
+    behaviour_assets: Res<BehaviourAssets>,
+    behaviours: Res<Assets<BehaviourDefinition>>,
+    research: Res<Research>,
     mut rng: ResMut<GlobalRng>,
-    mut next_ai_id: Local<u32>,
+    mut population: ResMut<Population>,
 ) -> Progress {
-    for i in *next_ai_id..u32::min(*next_ai_id + 100, NUM_AI) {
-        let pos_offset = crate::map::tile_xy_world_xy(map.width / 2, map.height / 2);
-        commands.spawn((
+    while population.spawned() < STARTING_VILLAGERS {
+        let position = crate::map::tile_xy_world_xy(map.width / 2, map.height / 2);
+        let name = population.next_name();
+        let spawned = spawn_villager(
+            &mut commands,
+            &sprite_assets,
+            &behaviour_assets,
+            &behaviours,
+            &research,
+            RngComponent::from(&mut rng),
+            position,
+            name,
+        );
+        if spawned.is_none() {
+            break;
+        }
+    }
+
+    Progress {
+        done: population.spawned(),
+        total: STARTING_VILLAGERS,
+    }
+}
+
+/// Spawns a villager with freshly rolled skills and behaviour, returning their entity.
+/// Nobody is spawned if there's no behaviour villagers can be given.
+pub fn spawn_villager(
+    commands: &mut Commands,
+    sprite_assets: &SpriteAssets,
+    behaviour_assets: &BehaviourAssets,
+    behaviours: &Assets<BehaviourDefinition>,
+    research: &Research,
+    mut villager_rng: RngComponent,
+    position: Vec2,
+    name: String,
+) -> Option<Entity> {
+    let skills = Skills::random(&mut villager_rng);
+    let schedule = DailySchedule::random(&mut villager_rng);
+    let behaviour = match behaviour_assets.choose(behaviours, &mut villager_rng) {
+        Some(behaviour) => behaviour,
+        None => {
+            error!("No behaviour in the behaviours folder has a weight above 0, villagers can't be spawned.");
+            return None;
+        }
+    };
+    let villager = commands
+        .spawn((
             SpriteSheetBundle {
                 sprite: TextureAtlasSprite::new(0),
                 texture_atlas: sprite_assets.villager.clone(),
                 transform: Transform {
-                    translation: pos_offset.extend(2.),
+                    translation: position.extend(2.),
                     ..default()
                 },
                 ..default()
             },
             Thirst::new(0.0, 0.1 * SIMULATION_SPEED),
-            Speed::new(1. * TILE_SIZE.x * SIMULATION_SPEED),
+            Hunger::new(0.0, 0.05 * SIMULATION_SPEED),
+            Energy::new(100.0, 0.04 * SIMULATION_SPEED),
+            Health::new(100.0),
+            Mood::default(),
+            Loneliness::new(0.0, 0.03 * SIMULATION_SPEED),
+            Relationships::default(),
+            Speed::new(1. * TILE_SIZE.x * SIMULATION_SPEED * research.speed_multiplier()),
             JobSeeker,
-            build_thinker(),
-            Name::from(format!("Villager {}", i)),
-            RngComponent::from(&mut rng),
+            skills,
+            Inventory::new(MAX_CARRY_WEIGHT),
+            behaviour.thinker(&mut villager_rng),
+            Archetype(behaviour.archetype.clone()),
+            Name::from(name),
+        ))
+        .insert((
+            schedule,
+            History::default(),
+            villager_rng,
             AnimationTimer(Timer::from_seconds(0.5, TimerMode::Repeating)),
-        ));
-
-        *next_ai_id += 1;
-    }
-
-    Progress {
-        done: *next_ai_id,
-        total: NUM_AI,
-    }
-}
-
-fn build_thinker() -> ThinkerBuilder {
-    let move_and_drink = Steps::build()
-        .step(WaterSourceDestination)
-        .step(MoveToDestination::default())
-        .step(Drink {
-            per_second: 10.0 * SIMULATION_SPEED,
-        });
-    let meander = Steps::build()
-        .step(RandomDestinationBuilder)
-        .step(MoveToDestination::default());
-    let take_and_do_jobs = Steps::build()
-        .step(TakingJob)
-        .step(JobDestination)
-        .step(MoveToDestination::default())
-        .step(DoJob);
-    Thinker::build()
-        .picker(HighestScore::new())
-        .when(Thirsty, move_and_drink)
-        .when(JobAvailable, take_and_do_jobs)
-        .when(FixedScore(0.5), meander)
+        ))
+        .id();
+    Some(villager)
 }
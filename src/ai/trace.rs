@@ -0,0 +1,327 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use bevy::prelude::*;
+use big_brain::prelude::*;
+use serde::Serialize;
+
+use crate::{gui::Notification, simulation::WorldClock};
+
+use super::{
+    actions::components::{ActionLabel, PickedOption},
+    characteristics::Health,
+    scorers::components::ScorerLabel,
+};
+
+// How many entries a villager's trace keeps, older ones are dropped first.
+const TRACE_LENGTH: usize = 200;
+// How often, in seconds, every scorer value is written down.
+const SCORE_SAMPLE_INTERVAL: f32 = 1.0;
+// File the traces of all villagers are exported to, one JSON object per line.
+const TRACE_EXPORT_PATH: &str = "decision_trace.jsonl";
+
+/// Whether villagers' decisions are being recorded, off unless the player asks for it.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct TraceRecorder {
+    pub recording: bool,
+}
+
+/// Sent to write the traces of every villager to disk.
+#[derive(Clone, Copy, Debug)]
+pub struct ExportTraces;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct ScoreSample {
+    pub scorer: &'static str,
+    pub score: f32,
+}
+
+#[derive(Clone, Debug, Serialize)]
+#[serde(tag = "event")]
+pub enum TraceEvent {
+    Scores {
+        scores: Vec<ScoreSample>,
+    },
+    Picked {
+        option: &'static str,
+        scores: Vec<ScoreSample>,
+    },
+    ActionState {
+        action: String,
+        state: &'static str,
+    },
+}
+
+impl TraceEvent {
+    pub fn describe(&self) -> String {
+        let describe_scores = |scores: &[ScoreSample]| {
+            scores
+                .iter()
+                .map(|sample| format!("{} {:.2}", sample.scorer, sample.score))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            TraceEvent::Scores { scores } => describe_scores(scores),
+            TraceEvent::Picked { option, scores } => format!("Picked {} ({})", option, describe_scores(scores)),
+            TraceEvent::ActionState { action, state } => format!("{}: {}", action, state),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct TraceEntry {
+    //Seconds since the game started
+    pub seconds: f64,
+    pub day: u32,
+    pub hour: f32,
+    #[serde(flatten)]
+    pub event: TraceEvent,
+}
+
+/// The latest decisions of a villager, recorded while tracing is on.
+#[derive(Component, Clone, Debug, Default)]
+pub struct DecisionTrace {
+    entries: VecDeque<TraceEntry>,
+}
+
+impl DecisionTrace {
+    pub fn record(&mut self, time: &Time, clock: &WorldClock, event: TraceEvent) {
+        self.entries.push_back(TraceEntry {
+            seconds: time.elapsed_seconds_f64(),
+            day: clock.day(),
+            hour: clock.hour(),
+            event,
+        });
+        while self.entries.len() > TRACE_LENGTH {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn entries(&self) -> impl DoubleEndedIterator<Item = &TraceEntry> {
+        self.entries.iter()
+    }
+}
+
+fn state_name(action_state: &ActionState) -> Option<&'static str> {
+    match action_state {
+        ActionState::Requested => Some("Requested"),
+        ActionState::Executing => Some("Executing"),
+        ActionState::Cancelled => Some("Cancelled"),
+        ActionState::Success => Some("Success"),
+        ActionState::Failure => Some("Failure"),
+        _ => None,
+    }
+}
+
+/// The current score of every labelled scorer, per villager.
+fn collect_scores(scorers: &Query<(&Actor, &Score, &ScorerLabel)>) -> HashMap<Entity, Vec<ScoreSample>> {
+    let mut scores: HashMap<Entity, Vec<ScoreSample>> = HashMap::new();
+    for (Actor(actor), score, ScorerLabel(label)) in scorers.iter() {
+        scores.entry(*actor).or_default().push(ScoreSample {
+            scorer: *label,
+            score: score.get(),
+        });
+    }
+    scores
+}
+
+pub fn start_tracing(
+    mut commands: Commands,
+    recorder: Res<TraceRecorder>,
+    villagers: Query<Entity, (With<Health>, Without<DecisionTrace>)>,
+) {
+    if !recorder.recording {
+        return;
+    }
+
+    for villager in villagers.iter() {
+        commands.entity(villager).insert(DecisionTrace::default());
+    }
+}
+
+pub fn trace_scores(
+    time: Res<Time>,
+    clock: Res<WorldClock>,
+    recorder: Res<TraceRecorder>,
+    mut timer: Local<Timer>,
+    scorers: Query<(&Actor, &Score, &ScorerLabel)>,
+    mut traces: Query<&mut DecisionTrace>,
+) {
+    if !recorder.recording {
+        return;
+    }
+    if timer.duration().is_zero() {
+        *timer = Timer::from_seconds(SCORE_SAMPLE_INTERVAL, TimerMode::Repeating);
+    }
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    for (villager, scores) in collect_scores(&scorers) {
+        if let Ok(mut trace) = traces.get_mut(villager) {
+            trace.record(&time, &clock, TraceEvent::Scores { scores });
+        }
+    }
+}
+
+pub fn trace_picks(
+    time: Res<Time>,
+    clock: Res<WorldClock>,
+    recorder: Res<TraceRecorder>,
+    picks: Query<(&Actor, &PickedOption), Added<PickedOption>>,
+    scorers: Query<(&Actor, &Score, &ScorerLabel)>,
+    mut traces: Query<&mut DecisionTrace>,
+) {
+    if !recorder.recording || picks.is_empty() {
+        return;
+    }
+
+    let mut scores = collect_scores(&scorers);
+    for (Actor(actor), PickedOption(option)) in picks.iter() {
+        if let Ok(mut trace) = traces.get_mut(*actor) {
+            let event = TraceEvent::Picked {
+                option: *option,
+                scores: scores.remove(actor).unwrap_or_default(),
+            };
+            trace.record(&time, &clock, event);
+        }
+    }
+}
+
+pub fn trace_action_states(
+    time: Res<Time>,
+    clock: Res<WorldClock>,
+    recorder: Res<TraceRecorder>,
+    //Last state recorded for every action still running
+    mut last_states: Local<HashMap<Entity, ActionState>>,
+    actions: Query<
+        (
+            Entity,
+            &Actor,
+            &ActionState,
+            Option<&ActionLabel>,
+            Option<&PickedOption>,
+        ),
+        Changed<ActionState>,
+    >,
+    mut traces: Query<&mut DecisionTrace>,
+) {
+    if !recorder.recording {
+        last_states.clear();
+        return;
+    }
+
+    for (action, Actor(actor), action_state, label, picked) in actions.iter() {
+        let state = match state_name(action_state) {
+            Some(state) => state,
+            None => continue,
+        };
+        if last_states.get(&action) == Some(action_state) {
+            //Set again without actually changing.
+            continue;
+        }
+        if matches!(action_state, ActionState::Success | ActionState::Failure) {
+            last_states.remove(&action);
+        } else {
+            last_states.insert(action, action_state.clone());
+        }
+
+        let name = match (label, picked) {
+            (Some(ActionLabel(label)), _) => label.to_string(),
+            (None, Some(PickedOption(option))) => format!("Steps for {}", option),
+            (None, None) => "Steps".to_string(),
+        };
+        if let Ok(mut trace) = traces.get_mut(*actor) {
+            trace.record(&time, &clock, TraceEvent::ActionState { action: name, state });
+        }
+    }
+}
+
+/// One line of an exported trace file.
+#[derive(Serialize)]
+struct ExportedEntry<'a> {
+    villager: &'a str,
+    #[serde(flatten)]
+    entry: &'a TraceEntry,
+}
+
+pub fn export_traces(
+    mut exports: EventReader<ExportTraces>,
+    traces: Query<(&Name, &DecisionTrace)>,
+    mut notifications: EventWriter<Notification>,
+) {
+    if exports.iter().count() == 0 {
+        return;
+    }
+
+    let write_traces = || -> Result<usize, Box<dyn std::error::Error>> {
+        let mut writer = BufWriter::new(File::create(TRACE_EXPORT_PATH)?);
+        let mut count = 0;
+        for (name, trace) in traces.iter() {
+            for entry in trace.entries() {
+                serde_json::to_writer(
+                    &mut writer,
+                    &ExportedEntry {
+                        villager: name.as_str(),
+                        entry,
+                    },
+                )?;
+                writeln!(writer)?;
+                count += 1;
+            }
+        }
+        writer.flush()?;
+        Ok(count)
+    };
+
+    let message = match write_traces() {
+        Ok(count) => format!("Exported {} trace entries to {}.", count, TRACE_EXPORT_PATH),
+        Err(error) => {
+            error!("Failed to export decision traces: {}", error);
+            "Failed to export decision traces.".to_string()
+        }
+    };
+    notifications.send(Notification(message));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn trace_is_bounded() {
+        let time = Time::default();
+        let clock = WorldClock::default();
+        let mut trace = DecisionTrace::default();
+        for _ in 0..TRACE_LENGTH + 10 {
+            trace.record(&time, &clock, TraceEvent::Scores { scores: Vec::new() });
+        }
+        assert_eq!(trace.entries().count(), TRACE_LENGTH);
+    }
+
+    #[test]
+    fn entries_export_as_flat_json() {
+        let entry = TraceEntry {
+            seconds: 1.5,
+            day: 2,
+            hour: 8.0,
+            event: TraceEvent::ActionState {
+                action: "Drinking".to_string(),
+                state: "Executing",
+            },
+        };
+        let line = serde_json::to_string(&ExportedEntry {
+            villager: "Villager 1",
+            entry: &entry,
+        })
+        .unwrap();
+        assert_eq!(
+            line,
+            r#"{"villager":"Villager 1","seconds":1.5,"day":2,"hour":8.0,"event":"ActionState","action":"Drinking","state":"Executing"}"#
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use bevy::prelude::*;
+use bevy_turborand::{DelegatedRng, GlobalRng, RngComponent};
+use iyes_progress::Progress;
+
+use crate::{
+    items::{ItemStack, Items},
+    map::{tile_xy_world_xy, Biomes, Map, TILE_SIZE},
+    SIMULATION_SPEED,
+};
+
+use super::{
+    behaviours::{BehaviourAssets, BehaviourDefinition},
+    characteristics::{Speed, Thirst},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Species {
+    Deer,
+    Rabbit,
+    Crab,
+}
+
+impl Species {
+    pub const ALL: [Species; 3] = [Species::Deer, Species::Rabbit, Species::Crab];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Species::Deer => "Deer",
+            Species::Rabbit => "Rabbit",
+            Species::Crab => "Crab",
+        }
+    }
+
+    /// Whether the species can be found living on a biome.
+    pub fn lives_in(&self, biome: Biomes) -> bool {
+        match self {
+            Species::Deer => matches!(
+                biome,
+                Biomes::TemperateDeciduousForest
+                    | Biomes::TemperateRainForest
+                    | Biomes::TropicalSeasonalForest
+                    | Biomes::TropicalRainForest
+                    | Biomes::Taiga
+            ),
+            Species::Rabbit => matches!(biome, Biomes::Grassland | Biomes::Shrubland),
+            Species::Crab => matches!(biome, Biomes::Beach),
+        }
+    }
+
+    /// How many animals of the species roam the map at the start of the game.
+    pub fn population(&self) -> usize {
+        match self {
+            Species::Deer => 20,
+            Species::Rabbit => 25,
+            Species::Crab => 15,
+        }
+    }
+
+    /// Walking speed, in tiles per second.
+    pub fn speed(&self) -> f32 {
+        match self {
+            Species::Deer => 1.6,
+            Species::Rabbit => 1.4,
+            Species::Crab => 0.6,
+        }
+    }
+
+    /// What is left of the animal once it has been hunted.
+    pub fn meat(&self) -> ItemStack {
+        match self {
+            Species::Deer => ItemStack::new(Items::Meat, 6),
+            Species::Rabbit => ItemStack::new(Items::Meat, 2),
+            Species::Crab => ItemStack::new(Items::Meat, 1),
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            Species::Deer => Color::rgb(0.6, 0.4, 0.2),
+            Species::Rabbit => Color::rgb(0.85, 0.8, 0.7),
+            Species::Crab => Color::rgb(0.9, 0.35, 0.2),
+        }
+    }
+
+    fn size(&self) -> f32 {
+        match self {
+            Species::Deer => 12.0,
+            Species::Rabbit => 8.0,
+            Species::Crab => 7.0,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Animal {
+    pub species: Species,
+}
+
+/// An animal that villagers have been ordered to hunt.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Hunted;
+
+pub fn spawn_wildlife(
+    mut commands: Commands,
+    map: Res<Map>,
+    behaviour_assets: Res<BehaviourAssets>,
+    behaviours: Res<Assets<BehaviourDefinition>>,
+    mut rng: ResMut<GlobalRng>,
+    animals: Query<(), With<Animal>>,
+) -> Progress {
+    if !animals.is_empty() {
+        //Already spawned on an earlier frame.
+        return true.into();
+    }
+
+    for species in Species::ALL {
+        let habitat: Vec<usize> = (0..map.tiles.len())
+            .filter(|idx| {
+                let tile = map.idx_tile_xy(*idx);
+                species.lives_in(map.tiles[*idx]) && map.is_passable(tile.x, tile.y)
+            })
+            .collect();
+        if habitat.is_empty() {
+            continue;
+        }
+
+        let behaviour = behaviour_assets.wildlife(&behaviours, species);
+        for _ in 0..species.population() {
+            let tile = map.idx_tile_xy(habitat[rng.usize(0..habitat.len())]);
+            let mut animal_rng = RngComponent::from(&mut rng);
+            commands.spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: species.color(),
+                        custom_size: Some(Vec2::splat(species.size())),
+                        ..default()
+                    },
+                    transform: Transform::from_translation(tile_xy_world_xy(tile.x, tile.y).extend(2.0)),
+                    ..default()
+                },
+                Animal { species },
+                Thirst::new(rng.f32() * 50.0, 0.05 * SIMULATION_SPEED),
+                Speed::new(species.speed() * TILE_SIZE.x * SIMULATION_SPEED),
+                behaviour.thinker(&mut animal_rng),
+                animal_rng,
+                Name::new(species.name()),
+            ));
+        }
+    }
+
+    true.into()
+}
@@ -4,8 +4,11 @@ use if_chain::if_chain;
 use iyes_loopless::prelude::*;
 
 use crate::{
+    gui::Notification,
     jobs::{JobCreation, JobSelectionType, SelectionStart},
+    map::Features,
     states::GameStates,
+    tech::Research,
 };
 
 #[derive(Component, Clone, Copy)]
@@ -16,6 +19,8 @@ pub struct InGameBuildUiRoot;
 pub enum InGameBuildUiElem {
     BuildRoadButton,
     BuildRoomButton,
+    BuildWellButton,
+    BuildBedButton,
 }
 
 impl InGameBuildUiElem {
@@ -23,6 +28,18 @@ impl InGameBuildUiElem {
         match self {
             InGameBuildUiElem::BuildRoadButton => "BuildRoadButton",
             InGameBuildUiElem::BuildRoomButton => "BuildRoomButton",
+            InGameBuildUiElem::BuildWellButton => "BuildWellButton",
+            InGameBuildUiElem::BuildBedButton => "BuildBedButton",
+        }
+    }
+
+    /// The feature the button builds, for buttons building a single feature.
+    fn feature(&self) -> Option<Features> {
+        match self {
+            InGameBuildUiElem::BuildRoadButton => Some(Features::Road),
+            InGameBuildUiElem::BuildRoomButton => None,
+            InGameBuildUiElem::BuildWellButton => Some(Features::Well),
+            InGameBuildUiElem::BuildBedButton => Some(Features::Bed),
         }
     }
 
@@ -52,20 +69,42 @@ pub fn update_build_menu_ui(
     mut commands: Commands,
     elements: Query<&InGameBuildUiElem>,
     current_state: Res<CurrentState<GameStates>>,
+    research: Res<Research>,
+    mut notifications: EventWriter<Notification>,
 ) {
     commands.remove_resource::<SelectionStart>();
 
     let mut requested_state_change = None;
     for button in events.nav_iter().activated_in_query(&elements) {
+        if let Some(feature) = button.feature() {
+            if !research.is_buildable(feature) {
+                if let Some(tech) = research.unlocking(feature) {
+                    notifications.send(Notification(format!(
+                        "{} has to be researched before building {:?}.",
+                        tech.name, feature
+                    )));
+                }
+                continue;
+            }
+        }
+
         match button {
             InGameBuildUiElem::BuildRoadButton => {
                 requested_state_change = Some(GameStates::InJobSelection);
-                commands.insert_resource(JobSelectionType(JobCreation::Build(crate::map::Features::Road)));
+                commands.insert_resource(JobSelectionType(JobCreation::Build(Features::Road)));
             }
             InGameBuildUiElem::BuildRoomButton => {
                 requested_state_change = Some(GameStates::InJobSelection);
                 commands.insert_resource(JobSelectionType(JobCreation::BuildRoom));
             }
+            InGameBuildUiElem::BuildWellButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Build(Features::Well)));
+            }
+            InGameBuildUiElem::BuildBedButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Build(Features::Bed)));
+            }
         }
     }
 
@@ -0,0 +1,290 @@
+use std::fmt::Write;
+
+use bevy::{math::Vec3Swizzles, prelude::*};
+use bevy_mouse_tracking_plugin::MousePosWorld;
+use big_brain::prelude::*;
+
+use crate::{
+    ai::{
+        actions::{
+            components::{ActionLabel, Destination, HasJob},
+            move_to_destination::MoveToDestination,
+        },
+        characteristics::{DailySchedule, Energy, Health, Hunger, Loneliness, Mood, Speed, Thirst},
+        history::History,
+        trace::DecisionTrace,
+        Archetype,
+    },
+    gui::UiAssets,
+    map::{tile_xy_world_xy, world_xy_tile_xy, TILE_SIZE},
+    simulation::WorldClock,
+};
+
+// Color of the markers showing the inspected villager and where they're headed.
+const SELECTION_COLOR: Color = Color::rgba(1.0, 1.0, 1.0, 0.35);
+const DESTINATION_COLOR: Color = Color::rgba(0.2, 0.6, 1.0, 0.35);
+// How many of the latest decision trace entries are shown while tracing.
+const TRACE_LINES: usize = 6;
+
+/// The villager shown in the inspector panel, if any.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct InspectedVillager(pub Option<Entity>);
+
+#[derive(Component, Clone, Copy)]
+pub struct InspectorPanelRoot;
+
+#[derive(Component, Clone, Copy)]
+pub struct InspectorPanelText;
+
+#[derive(Component, Clone, Copy)]
+pub enum InspectorMarker {
+    Selection,
+    Destination,
+}
+
+pub fn setup_inspector_panel(
+    mut commands: Commands,
+    ui_assets: Res<UiAssets>,
+    panel_query: Query<Entity, With<InspectorPanelRoot>>,
+) {
+    if !panel_query.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        left: Val::Px(15.0),
+                        bottom: Val::Px(140.0),
+                        ..default()
+                    },
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                visibility: Visibility { is_visible: false },
+                ..default()
+            },
+            InspectorPanelRoot,
+            Name::new("InspectorPanel"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((ui_assets.text_bundle("", 16.0), InspectorPanelText));
+        });
+
+    for (marker, color) in [
+        (InspectorMarker::Selection, SELECTION_COLOR),
+        (InspectorMarker::Destination, DESTINATION_COLOR),
+    ] {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(TILE_SIZE.x, TILE_SIZE.y)),
+                    ..default()
+                },
+                visibility: Visibility { is_visible: false },
+                ..default()
+            },
+            marker,
+            Name::new("InspectorMarker"),
+        ));
+    }
+}
+
+/// Inspects the villager under the mouse when clicking the map, or stops inspecting when clicking next to them.
+pub fn select_villager(
+    mouse_buttons: Res<Input<MouseButton>>,
+    mouse_pos: Res<MousePosWorld>,
+    interactions: Query<&Interaction>,
+    villagers: Query<(Entity, &Transform), With<Health>>,
+    mut inspected: ResMut<InspectedVillager>,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+    if interactions.iter().any(|interaction| *interaction != Interaction::None) {
+        //The click was meant for the UI.
+        return;
+    }
+
+    let click = mouse_pos.xy();
+    inspected.0 = villagers
+        .iter()
+        .map(|(villager, transform)| (villager, transform.translation.xy().distance(click)))
+        .filter(|(_, distance)| *distance <= TILE_SIZE.x)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).unwrap())
+        .map(|(villager, _)| villager);
+}
+
+pub fn update_inspector_panel(
+    clock: Res<WorldClock>,
+    mut inspected: ResMut<InspectedVillager>,
+    villagers: Query<(
+        &Name,
+        &Transform,
+        Option<&Archetype>,
+        &Health,
+        Option<&Thirst>,
+        Option<&Hunger>,
+        Option<&Energy>,
+        Option<&Mood>,
+        Option<&Loneliness>,
+        Option<&Speed>,
+        Option<&DailySchedule>,
+        Option<&HasJob>,
+        Option<&Destination>,
+        Option<&History>,
+        Option<&DecisionTrace>,
+    )>,
+    actions: Query<(&Actor, &ActionState, &ActionLabel)>,
+    moves: Query<(&Actor, &MoveToDestination)>,
+    mut panels: Query<&mut Visibility, (With<InspectorPanelRoot>, Without<InspectorMarker>)>,
+    mut texts: Query<&mut Text, With<InspectorPanelText>>,
+    mut markers: Query<(&mut Transform, &mut Visibility, &InspectorMarker), Without<Health>>,
+) {
+    let villager = inspected
+        .0
+        .and_then(|villager| villagers.get(villager).ok().map(|found| (villager, found)));
+    let (villager, components) = match villager {
+        Some(villager) => villager,
+        None => {
+            //Nobody is inspected, or they have died.
+            inspected.0 = None;
+            for mut visibility in panels.iter_mut() {
+                visibility.is_visible = false;
+            }
+            for (_, mut visibility, _) in markers.iter_mut() {
+                visibility.is_visible = false;
+            }
+            return;
+        }
+    };
+    let (
+        name,
+        transform,
+        archetype,
+        health,
+        thirst,
+        hunger,
+        energy,
+        mood,
+        loneliness,
+        speed,
+        schedule,
+        has_job,
+        destination,
+        history,
+        trace,
+    ) = components;
+
+    let mut text = match archetype {
+        Some(Archetype(archetype)) => format!("{} ({})\n", name, archetype),
+        None => format!("{}\n", name),
+    };
+    writeln!(text, "Health {:.0} ({})", health.health, health.condition.name()).unwrap();
+    let needs: Vec<_> = [
+        thirst.map(|thirst| format!("Thirst {:.0}", thirst.thirst)),
+        hunger.map(|hunger| format!("Hunger {:.0}", hunger.hunger)),
+        energy.map(|energy| format!("Energy {:.0}", energy.energy)),
+        loneliness.map(|loneliness| format!("Loneliness {:.0}", loneliness.loneliness)),
+        mood.map(|mood| format!("Mood {:.0}", mood.mood)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !needs.is_empty() {
+        writeln!(text, "{}", needs.join(", ")).unwrap();
+    }
+    if let Some(schedule) = schedule {
+        writeln!(text, "Schedule: {}", schedule.activity(clock.hour()).name()).unwrap();
+    }
+
+    let doing: Vec<_> = actions
+        .iter()
+        .filter(|(Actor(actor), action_state, _)| *actor == villager && **action_state == ActionState::Executing)
+        .map(|(_, _, ActionLabel(label))| *label)
+        .collect();
+    if doing.is_empty() {
+        writeln!(text, "Doing: nothing").unwrap();
+    } else {
+        writeln!(text, "Doing: {}", doing.join(", ")).unwrap();
+    }
+    if let Some(has_job) = has_job {
+        writeln!(
+            text,
+            "Job: {:?} at ({}, {}), {:.0}% done",
+            has_job.job.job_type, has_job.job.position.x, has_job.job.position.y, has_job.progress
+        )
+        .unwrap();
+    } else {
+        writeln!(text, "Job: none").unwrap();
+    }
+    if let Some(destination) = destination {
+        let move_to = moves
+            .iter()
+            .find(|(Actor(actor), _)| *actor == villager)
+            .map(|(_, move_to)| move_to);
+        let next = move_to.and_then(|move_to| move_to.next);
+        let next = next.map_or(String::new(), |next| format!(", next step ({}, {})", next.x, next.y));
+        //Walking a copy of the path leaves the villager's own path untouched.
+        let remaining = move_to.and_then(|move_to| {
+            let path = move_to.path.clone()?;
+            Some(path.count() + usize::from(move_to.next.is_some()))
+        });
+        let remaining = remaining.map_or(String::new(), |remaining| format!(", {} tiles to go", remaining));
+        writeln!(
+            text,
+            "Destination: ({}, {}){}{}",
+            destination.destination.x, destination.destination.y, next, remaining
+        )
+        .unwrap();
+    }
+    if let Some(speed) = speed {
+        let speed = speed.speed * health.speed_multiplier() / TILE_SIZE.x;
+        writeln!(text, "Speed {:.1} tiles/s", speed).unwrap();
+    }
+    if let Some(history) = history {
+        writeln!(text, "Recently:").unwrap();
+        for entry in history.entries() {
+            writeln!(
+                text,
+                "  Day {} {:02}:{:02} {}",
+                entry.day,
+                entry.hour.floor() as u32,
+                (entry.hour.fract() * 60.0).floor() as u32,
+                entry.text
+            )
+            .unwrap();
+        }
+    }
+    if let Some(trace) = trace {
+        writeln!(text, "Trace:").unwrap();
+        let latest: Vec<_> = trace.entries().rev().take(TRACE_LINES).collect();
+        for entry in latest.into_iter().rev() {
+            writeln!(text, "  {:.1}s {}", entry.seconds, entry.event.describe()).unwrap();
+        }
+    }
+
+    for mut visibility in panels.iter_mut() {
+        visibility.is_visible = true;
+    }
+    for mut panel_text in texts.iter_mut() {
+        panel_text.sections[0].value = text.trim_end().to_string();
+    }
+
+    let villager_tile = world_xy_tile_xy(transform.translation.xy());
+    for (mut marker_transform, mut visibility, marker) in markers.iter_mut() {
+        let tile = match marker {
+            InspectorMarker::Selection => Some(villager_tile),
+            InspectorMarker::Destination => destination.map(|destination| destination.destination),
+        };
+        visibility.is_visible = tile.is_some();
+        if let Some(tile) = tile {
+            marker_transform.translation = tile_xy_world_xy(tile.x, tile.y).extend(1.9);
+        }
+    }
+}
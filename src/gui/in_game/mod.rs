@@ -15,7 +15,13 @@ use crate::{
 use super::UiAssets;
 
 mod build;
+mod inspector;
+mod notifications;
 mod orders;
+mod overlays;
+mod research;
+mod resources;
+mod zones;
 
 #[derive(Component, Clone, Copy)]
 struct InGameUiRoot;
@@ -25,6 +31,9 @@ struct InGameUiRoot;
 pub enum InGameUiMenu {
     OrdersMenu,
     BuildMenu,
+    ZonesMenu,
+    OverlaysMenu,
+    ResearchMenu,
 }
 
 impl InGameUiMenu {
@@ -32,6 +41,9 @@ impl InGameUiMenu {
         match self {
             InGameUiMenu::OrdersMenu => "OrdersMenuButton",
             InGameUiMenu::BuildMenu => "BuildMenuButton",
+            InGameUiMenu::ZonesMenu => "ZonesMenuButton",
+            InGameUiMenu::OverlaysMenu => "OverlaysMenuButton",
+            InGameUiMenu::ResearchMenu => "ResearchMenuButton",
         }
     }
 
@@ -53,13 +65,35 @@ pub struct InGameGuiPlugin;
 
 impl Plugin for InGameGuiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_enter_system(GameStates::InGame, setup_in_game_ui)
+        app.init_resource::<inspector::InspectedVillager>()
+            .add_enter_system(GameStates::InGame, setup_in_game_ui)
+            .add_enter_system(GameStates::InGame, resources::setup_resources_panel)
+            .add_enter_system(GameStates::InGame, research::setup_research_panel)
+            .add_enter_system(GameStates::InGame, notifications::setup_notifications_panel)
+            .add_enter_system(GameStates::InGame, inspector::setup_inspector_panel)
+            .add_system(inspector::select_villager.run_in_state(GameStates::InGame))
+            .add_system_set(
+                condition_set_in_states!(GameStates::InGame | GameStates::InJobSelection)
+                    .with_system(resources::update_clock)
+                    .with_system(resources::update_resources_panel)
+                    .with_system(resources::update_capacity_estimate)
+                    .with_system(resources::update_population)
+                    .with_system(resources::update_town_mood)
+                    .with_system(research::add_tech_buttons)
+                    .with_system(research::update_research_panel)
+                    .with_system(notifications::update_notifications_panel)
+                    .with_system(inspector::update_inspector_panel)
+                    .into(),
+            )
             .add_system_set(
                 condition_set_in_states!(GameStates::InGame | GameStates::InJobSelection)
                     .run_on_event::<NavEvent>()
                     .with_system(show_menus)
                     .with_system(orders::update_orders_menu_ui)
                     .with_system(build::update_build_menu_ui)
+                    .with_system(zones::update_zones_menu_ui)
+                    .with_system(overlays::update_overlays_menu_ui)
+                    .with_system(research::update_research_menu_ui)
                     .into(),
             )
             // TODO!(2, Wayan, 8) : Don't hide menu when in job selection. Probably needs to hook into keyboard
@@ -139,6 +173,21 @@ fn setup_in_game_ui(
                                 display: Display::None
                             }[orders::InGameOrdersUiElem::ClearButton.to_button();](
                                 node[text_bundle("Clear", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[orders::InGameOrdersUiElem::HarvestButton.to_button();](
+                                node[text_bundle("Harvest", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[orders::InGameOrdersUiElem::HuntButton.to_button();](
+                                node[text_bundle("Hunt", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[orders::InGameOrdersUiElem::DeconstructButton.to_button();](
+                                node[text_bundle("Deconstruct", 20.0);]
                             )
                         )
                     ),
@@ -160,8 +209,86 @@ fn setup_in_game_ui(
                                 display: Display::None
                             }[build::InGameBuildUiElem::BuildRoomButton.to_button();](
                                 node[text_bundle("Room", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[build::InGameBuildUiElem::BuildWellButton.to_button();](
+                                node[text_bundle("Well", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[build::InGameBuildUiElem::BuildBedButton.to_button();](
+                                node[text_bundle("Bed", 20.0);]
                             )
                         )
+                    ),
+                    node{
+                        flex_direction: FlexDirection::Column
+                    }[;](
+                        button {}[InGameUiMenu::ZonesMenu.to_button();](
+                            node[text_bundle("Zones", 20.0);]
+                        ),
+                        node {
+                            flex_direction: FlexDirection::Column
+                        }[InGameUiMenu::ZonesMenu.to_menu(); zones::InGameZonesUiRoot](
+                            button {
+                                display: Display::None
+                            }[zones::InGameZonesUiElem::StockpileButton.to_button();](
+                                node[text_bundle("Stockpile", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[zones::InGameZonesUiElem::FoodStockpileButton.to_button();](
+                                node[text_bundle("Food", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[zones::InGameZonesUiElem::MaterialStockpileButton.to_button();](
+                                node[text_bundle("Materials", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[zones::InGameZonesUiElem::FarmButton.to_button();](
+                                node[text_bundle("Farm", 20.0);]
+                            )
+                        )
+                    ),
+                    node{
+                        flex_direction: FlexDirection::Column
+                    }[;](
+                        button {}[InGameUiMenu::OverlaysMenu.to_button();](
+                            node[text_bundle("Overlays", 20.0);]
+                        ),
+                        node {
+                            flex_direction: FlexDirection::Column
+                        }[InGameUiMenu::OverlaysMenu.to_menu(); overlays::InGameOverlaysUiRoot](
+                            button {
+                                display: Display::None
+                            }[overlays::InGameOverlaysUiElem::ReachButton.to_button();](
+                                node[text_bundle("Reach", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[overlays::InGameOverlaysUiElem::TraceButton.to_button();](
+                                node[text_bundle("Trace AI", 20.0);]
+                            ),
+                            button {
+                                display: Display::None
+                            }[overlays::InGameOverlaysUiElem::ExportTraceButton.to_button();](
+                                node[text_bundle("Export trace", 20.0);]
+                            )
+                        )
+                    ),
+                    node{
+                        flex_direction: FlexDirection::Column
+                    }[;](
+                        button {}[InGameUiMenu::ResearchMenu.to_button();](
+                            node[text_bundle("Research", 20.0);]
+                        ),
+                        //Filled with a button for every tech once the tech tree is known.
+                        node {
+                            flex_direction: FlexDirection::Column
+                        }[InGameUiMenu::ResearchMenu.to_menu(); research::InGameResearchUiRoot]
                     )
                 )
             )
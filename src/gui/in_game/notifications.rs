@@ -0,0 +1,89 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+
+use crate::gui::{Notification, UiAssets};
+
+// How long a notification stays on screen, in seconds.
+const NOTIFICATION_DURATION: f32 = 10.0;
+// How many notifications are shown at once, older ones are dropped first.
+const MAX_NOTIFICATIONS: usize = 5;
+
+#[derive(Component, Clone, Copy)]
+pub struct NotificationsPanelRoot;
+
+#[derive(Component, Clone, Copy)]
+pub struct NotificationsPanelText;
+
+pub fn setup_notifications_panel(
+    mut commands: Commands,
+    ui_assets: Res<UiAssets>,
+    panel_query: Query<Entity, With<NotificationsPanelRoot>>,
+) {
+    if !panel_query.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        left: Val::Px(15.0),
+                        bottom: Val::Px(15.0),
+                        ..default()
+                    },
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                visibility: Visibility { is_visible: false },
+                ..default()
+            },
+            NotificationsPanelRoot,
+            Name::new("NotificationsPanel"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((ui_assets.text_bundle("", 16.0), NotificationsPanelText));
+        });
+}
+
+pub fn update_notifications_panel(
+    time: Res<Time>,
+    mut notifications: EventReader<Notification>,
+    mut shown: Local<VecDeque<(String, Timer)>>,
+    mut panels: Query<&mut Visibility, With<NotificationsPanelRoot>>,
+    mut texts: Query<&mut Text, With<NotificationsPanelText>>,
+) {
+    let mut changed = false;
+    for Notification(message) in notifications.iter() {
+        shown.push_back((
+            message.clone(),
+            Timer::from_seconds(NOTIFICATION_DURATION, TimerMode::Once),
+        ));
+        changed = true;
+    }
+    while shown.len() > MAX_NOTIFICATIONS {
+        shown.pop_front();
+    }
+
+    for (_, timer) in shown.iter_mut() {
+        timer.tick(time.delta());
+    }
+    let before = shown.len();
+    shown.retain(|(_, timer)| !timer.finished());
+    changed |= shown.len() != before;
+
+    if !changed {
+        return;
+    }
+
+    for mut visibility in panels.iter_mut() {
+        visibility.is_visible = !shown.is_empty();
+    }
+    let lines: Vec<_> = shown.iter().map(|(message, _)| message.as_str()).collect();
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = lines.join("\n");
+    }
+}
@@ -17,6 +17,9 @@ pub enum InGameOrdersUiElem {
     ChopButton,
     MineButton,
     ClearButton,
+    HarvestButton,
+    HuntButton,
+    DeconstructButton,
 }
 
 impl InGameOrdersUiElem {
@@ -25,6 +28,9 @@ impl InGameOrdersUiElem {
             InGameOrdersUiElem::ChopButton => "ChopButton",
             InGameOrdersUiElem::MineButton => "MineButton",
             InGameOrdersUiElem::ClearButton => "ClearButton",
+            InGameOrdersUiElem::HarvestButton => "HarvestButton",
+            InGameOrdersUiElem::HuntButton => "HuntButton",
+            InGameOrdersUiElem::DeconstructButton => "DeconstructButton",
         }
     }
 
@@ -72,6 +78,18 @@ pub fn update_orders_menu_ui(
                 requested_state_change = Some(GameStates::InJobSelection);
                 commands.insert_resource(JobSelectionType(JobCreation::Clear));
             }
+            InGameOrdersUiElem::HarvestButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Harvest));
+            }
+            InGameOrdersUiElem::HuntButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Hunt));
+            }
+            InGameOrdersUiElem::DeconstructButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Deconstruct));
+            }
         }
     }
 
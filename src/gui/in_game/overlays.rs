@@ -0,0 +1,77 @@
+use bevy::prelude::*;
+use bevy_ui_navigation::prelude::*;
+
+use crate::{
+    ai::trace::{ExportTraces, TraceRecorder},
+    gui::Notification,
+    logistics::ReachOverlay,
+};
+
+#[derive(Component, Clone, Copy)]
+pub struct InGameOverlaysUiRoot;
+
+#[derive(Component, Clone, Copy)]
+pub enum InGameOverlaysUiElem {
+    ReachButton,
+    TraceButton,
+    ExportTraceButton,
+}
+
+impl InGameOverlaysUiElem {
+    fn name(&self) -> &str {
+        match self {
+            InGameOverlaysUiElem::ReachButton => "ReachButton",
+            InGameOverlaysUiElem::TraceButton => "TraceButton",
+            InGameOverlaysUiElem::ExportTraceButton => "ExportTraceButton",
+        }
+    }
+
+    pub fn to_button(
+        self,
+    ) -> (
+        Button,
+        Focusable,
+        Interaction,
+        Name,
+        InGameOverlaysUiElem,
+        super::InGameUiMenu,
+    ) {
+        (
+            Button,
+            Focusable::new(),
+            Interaction::None,
+            Name::new(self.name().to_string()),
+            self,
+            super::InGameUiMenu::OverlaysMenu,
+        )
+    }
+}
+
+pub fn update_overlays_menu_ui(
+    mut events: EventReader<NavEvent>,
+    elements: Query<&InGameOverlaysUiElem>,
+    mut reach_overlay: ResMut<ReachOverlay>,
+    mut trace_recorder: ResMut<TraceRecorder>,
+    mut trace_exports: EventWriter<ExportTraces>,
+    mut notifications: EventWriter<Notification>,
+) {
+    for button in events.nav_iter().activated_in_query(&elements) {
+        match button {
+            InGameOverlaysUiElem::ReachButton => {
+                reach_overlay.visible = !reach_overlay.visible;
+            }
+            InGameOverlaysUiElem::TraceButton => {
+                trace_recorder.recording = !trace_recorder.recording;
+                let message = if trace_recorder.recording {
+                    "Started tracing villager decisions."
+                } else {
+                    "Stopped tracing villager decisions."
+                };
+                notifications.send(Notification(message.to_string()));
+            }
+            InGameOverlaysUiElem::ExportTraceButton => {
+                trace_exports.send(ExportTraces);
+            }
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy_ui_navigation::prelude::*;
+
+use crate::{
+    gui::{Notification, UiAssets},
+    tech::{Research, TechId},
+};
+
+#[derive(Component, Clone, Copy)]
+pub struct InGameResearchUiRoot;
+
+#[derive(Component, Clone)]
+pub enum InGameResearchUiElem {
+    TechButton(TechId),
+}
+
+impl InGameResearchUiElem {
+    fn name(&self) -> String {
+        match self {
+            InGameResearchUiElem::TechButton(tech) => format!("{}TechButton", tech.0),
+        }
+    }
+
+    pub fn to_button(
+        self,
+    ) -> (
+        Button,
+        Focusable,
+        Interaction,
+        Name,
+        InGameResearchUiElem,
+        super::InGameUiMenu,
+    ) {
+        (
+            Button,
+            Focusable::new(),
+            Interaction::None,
+            Name::new(self.name()),
+            self,
+            super::InGameUiMenu::ResearchMenu,
+        )
+    }
+}
+
+/// Shows what is being researched and what has been researched already.
+#[derive(Component, Clone, Copy)]
+pub struct ResearchPanelText;
+
+/// Adds a button for every tech in the tech tree to the research menu.
+pub fn add_tech_buttons(
+    mut commands: Commands,
+    ui_assets: Res<UiAssets>,
+    research: Res<Research>,
+    menus: Query<Entity, Added<InGameResearchUiRoot>>,
+) {
+    for menu in menus.iter() {
+        commands.entity(menu).with_children(|menu| {
+            for definition in research.techs() {
+                menu.spawn((
+                    NodeBundle {
+                        style: Style {
+                            display: Display::None,
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::all(Val::Px(20.0)),
+                            margin: UiRect::all(Val::Px(5.0)),
+                            size: Size::new(Val::Px(110.0), Val::Px(60.0)),
+                            ..default()
+                        },
+                        ..default()
+                    },
+                    InGameResearchUiElem::TechButton(definition.id.clone()).to_button(),
+                ))
+                .with_children(|button| {
+                    button.spawn(ui_assets.text_bundle(&definition.name, 20.0));
+                });
+            }
+        });
+    }
+}
+
+pub fn update_research_menu_ui(
+    mut events: EventReader<NavEvent>,
+    elements: Query<&InGameResearchUiElem>,
+    mut research: ResMut<Research>,
+    mut notifications: EventWriter<Notification>,
+) {
+    for button in events.nav_iter().activated_in_query(&elements) {
+        match button {
+            InGameResearchUiElem::TechButton(tech) => {
+                let definition = match research.definition(tech) {
+                    Some(definition) => definition,
+                    None => continue,
+                };
+                if research.is_researched(tech) {
+                    notifications.send(Notification(format!(
+                        "{} has already been researched.",
+                        definition.name
+                    )));
+                } else if !research.can_research(tech) {
+                    let missing: Vec<_> = definition
+                        .prerequisites
+                        .iter()
+                        .filter(|prerequisite| !research.is_researched(prerequisite))
+                        .filter_map(|prerequisite| research.definition(prerequisite))
+                        .map(|prerequisite| prerequisite.name.as_str())
+                        .collect();
+                    notifications.send(Notification(format!(
+                        "{} first needs {}.",
+                        definition.name,
+                        missing.join(", ")
+                    )));
+                } else {
+                    research.select(tech);
+                }
+            }
+        }
+    }
+}
+
+pub fn setup_research_panel(
+    mut commands: Commands,
+    ui_assets: Res<UiAssets>,
+    panel_query: Query<Entity, With<ResearchPanelText>>,
+) {
+    if !panel_query.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        right: Val::Px(15.0),
+                        bottom: Val::Px(15.0),
+                        ..default()
+                    },
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            Name::new("ResearchPanel"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((ui_assets.text_bundle("", 16.0), ResearchPanelText));
+        });
+}
+
+pub fn update_research_panel(research: Res<Research>, mut texts: Query<&mut Text, With<ResearchPanelText>>) {
+    let current = match research.current() {
+        Some(definition) => format!("Researching {} ({:.0}%)", definition.name, research.progress() * 100.0),
+        None => "Not researching anything".to_string(),
+    };
+    let researched: Vec<_> = research
+        .techs()
+        .filter(|definition| research.is_researched(&definition.id))
+        .map(|definition| definition.name.as_str())
+        .collect();
+
+    for mut text in texts.iter_mut() {
+        text.sections[0].value = if researched.is_empty() {
+            current.clone()
+        } else {
+            format!("{}\nKnown: {}", current, researched.join(", "))
+        };
+    }
+}
@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::{
+    ai::{characteristics::Mood, Population},
+    gui::UiAssets,
+    items::{Items, TownInventory},
+    logistics::LogisticReach,
+    simulation::WorldClock,
+};
+
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelRoot;
+
+/// The line of the resources panel showing the day and time.
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelClock;
+
+/// A line of the resources panel showing the counts of one item.
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelRow(Items);
+
+/// The line of the resources panel estimating how many villagers the town can supply.
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelCapacity;
+
+/// The line of the resources panel showing how many villagers live in town, and how many it can support.
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelPopulation;
+
+/// The line of the resources panel showing the average mood of the town.
+#[derive(Component, Clone, Copy)]
+pub struct ResourcesPanelMood;
+
+pub fn setup_resources_panel(
+    mut commands: Commands,
+    ui_assets: Res<UiAssets>,
+    panel_query: Query<Entity, With<ResourcesPanelRoot>>,
+) {
+    if !panel_query.is_empty() {
+        return;
+    }
+
+    commands
+        .spawn((
+            NodeBundle {
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.6).into(),
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    position: UiRect {
+                        right: Val::Px(15.0),
+                        top: Val::Px(15.0),
+                        ..default()
+                    },
+                    flex_direction: FlexDirection::Column,
+                    padding: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+                ..default()
+            },
+            ResourcesPanelRoot,
+            Name::new("ResourcesPanel"),
+        ))
+        .with_children(|panel| {
+            panel.spawn((ui_assets.text_bundle("", 16.0), ResourcesPanelClock));
+            for item in Items::ALL {
+                panel.spawn((ui_assets.text_bundle("", 16.0), ResourcesPanelRow(item)));
+            }
+            panel.spawn((ui_assets.text_bundle("", 16.0), ResourcesPanelCapacity));
+            panel.spawn((ui_assets.text_bundle("", 16.0), ResourcesPanelPopulation));
+            panel.spawn((ui_assets.text_bundle("", 16.0), ResourcesPanelMood));
+        });
+}
+
+pub fn update_clock(clock: Res<WorldClock>, mut rows: Query<&mut Text, With<ResourcesPanelClock>>) {
+    let hour = clock.hour();
+    for mut text in rows.iter_mut() {
+        text.sections[0].value = format!(
+            "Day {}, {:02}:{:02} ({})",
+            clock.day(),
+            hour.floor() as u32,
+            (hour.fract() * 60.0).floor() as u32,
+            clock.time_of_day().name()
+        );
+    }
+}
+
+pub fn update_resources_panel(town_inventory: Res<TownInventory>, mut rows: Query<(&mut Text, &ResourcesPanelRow)>) {
+    for (mut text, ResourcesPanelRow(item)) in rows.iter_mut() {
+        let counts = town_inventory.counts(*item);
+        let trend = match town_inventory.trend(*item) {
+            Ordering::Greater => "↑",
+            Ordering::Less => "↓",
+            Ordering::Equal => "→",
+        };
+        text.sections[0].value = format!(
+            "{:<6}{:>4} {} (reserved {}, carried {})",
+            item.name(),
+            counts.stored,
+            trend,
+            counts.reserved,
+            counts.in_transit
+        );
+    }
+}
+
+pub fn update_capacity_estimate(reach: Res<LogisticReach>, mut rows: Query<&mut Text, With<ResourcesPanelCapacity>>) {
+    if !reach.is_changed() {
+        return;
+    }
+
+    for mut text in rows.iter_mut() {
+        text.sections[0].value = format!("Capacity ~{} villagers", reach.capacity());
+    }
+}
+
+pub fn update_population(
+    population: Res<Population>,
+    villagers: Query<(), With<Mood>>,
+    mut rows: Query<&mut Text, With<ResourcesPanelPopulation>>,
+) {
+    let size = villagers.iter().count();
+    for mut text in rows.iter_mut() {
+        text.sections[0].value = format!("Population {} (room for {})", size, population.capacity());
+    }
+}
+
+pub fn update_town_mood(moods: Query<&Mood>, mut rows: Query<&mut Text, With<ResourcesPanelMood>>) {
+    let count = moods.iter().count();
+    let average = if count == 0 {
+        0.0
+    } else {
+        moods.iter().map(|mood| mood.mood).sum::<f32>() / count as f32
+    };
+
+    for mut text in rows.iter_mut() {
+        text.sections[0].value = format!("Mood {:.0}%", average);
+    }
+}
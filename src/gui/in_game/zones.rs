@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy_ui_navigation::prelude::*;
+use if_chain::if_chain;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    items::ItemCategory,
+    jobs::{JobCreation, JobSelectionType, SelectionStart},
+    states::GameStates,
+    zones::ItemFilter,
+};
+
+#[derive(Component, Clone, Copy)]
+pub struct InGameZonesUiRoot;
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Component, Clone, Copy)]
+pub enum InGameZonesUiElem {
+    StockpileButton,
+    FoodStockpileButton,
+    MaterialStockpileButton,
+    FarmButton,
+}
+
+impl InGameZonesUiElem {
+    fn name(&self) -> &str {
+        match self {
+            InGameZonesUiElem::StockpileButton => "StockpileButton",
+            InGameZonesUiElem::FoodStockpileButton => "FoodStockpileButton",
+            InGameZonesUiElem::MaterialStockpileButton => "MaterialStockpileButton",
+            InGameZonesUiElem::FarmButton => "FarmButton",
+        }
+    }
+
+    pub fn to_button(
+        self,
+    ) -> (
+        Button,
+        Focusable,
+        Interaction,
+        Name,
+        InGameZonesUiElem,
+        super::InGameUiMenu,
+    ) {
+        (
+            Button,
+            Focusable::new(),
+            Interaction::None,
+            Name::new(self.name().to_string()),
+            self,
+            super::InGameUiMenu::ZonesMenu,
+        )
+    }
+}
+
+pub fn update_zones_menu_ui(
+    mut events: EventReader<NavEvent>,
+    mut commands: Commands,
+    elements: Query<&InGameZonesUiElem>,
+    current_state: Res<CurrentState<GameStates>>,
+) {
+    commands.remove_resource::<SelectionStart>();
+
+    let mut requested_state_change = None;
+    for button in events.nav_iter().activated_in_query(&elements) {
+        match button {
+            InGameZonesUiElem::StockpileButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Stockpile(ItemFilter::All)));
+            }
+            InGameZonesUiElem::FoodStockpileButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Stockpile(ItemFilter::Only(
+                    ItemCategory::Food,
+                ))));
+            }
+            InGameZonesUiElem::MaterialStockpileButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Stockpile(ItemFilter::Only(
+                    ItemCategory::Material,
+                ))));
+            }
+            InGameZonesUiElem::FarmButton => {
+                requested_state_change = Some(GameStates::InJobSelection);
+                commands.insert_resource(JobSelectionType(JobCreation::Farm));
+            }
+        }
+    }
+
+    if_chain! {
+        if let Some(request) = requested_state_change;
+        if request != current_state.0;
+        then {
+            commands.insert_resource(NextState(request));
+        }
+    }
+}
@@ -27,6 +27,9 @@ impl UiAssets {
     }
 }
 
+/// A message shown to the player for a little while, about something that happened in town.
+pub struct Notification(pub String);
+
 #[allow(dead_code)]
 fn hide_ui_by_root_component<T: Component>(mut root_query: Query<&mut Visibility, With<T>>) {
     root_query.single_mut().is_visible = false;
@@ -36,7 +39,8 @@ pub struct GuiPlugin;
 
 impl Plugin for GuiPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugin(NavigationPlugin::new())
+        app.add_event::<Notification>()
+            .add_plugin(NavigationPlugin::new())
             .add_plugin(navigation::NavigationSystemsPlugin)
             .add_system(systems::button_system.after(NavRequestSystem));
 
@@ -0,0 +1,201 @@
+use bevy::prelude::*;
+
+use super::{ItemStack, Items};
+
+/// Holds stacks of items, limited either by number of stacks or by total weight.
+#[derive(Component, Clone, Debug, Default)]
+pub struct Container {
+    stacks: Vec<ItemStack>,
+    max_stacks: Option<usize>,
+    max_weight: Option<f32>,
+}
+
+impl Container {
+    pub fn with_slots(max_stacks: usize) -> Self {
+        Self {
+            max_stacks: Some(max_stacks),
+            ..default()
+        }
+    }
+
+    pub fn with_weight(max_weight: f32) -> Self {
+        Self {
+            max_weight: Some(max_weight),
+            ..default()
+        }
+    }
+
+    pub fn stacks(&self) -> &[ItemStack] {
+        &self.stacks
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stacks.is_empty()
+    }
+
+    pub fn count(&self, item: Items) -> u32 {
+        self.stacks.iter().filter(|s| s.item == item).map(|s| s.count).sum()
+    }
+
+    pub fn available(&self, item: Items) -> u32 {
+        self.stacks
+            .iter()
+            .filter(|s| s.item == item)
+            .map(|s| s.available())
+            .sum()
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.stacks.iter().map(|s| s.weight()).sum()
+    }
+
+    /// How many more of `item` this container can hold.
+    pub fn capacity_for(&self, item: Items) -> u32 {
+        let by_weight = self.max_weight.map_or(u32::MAX, |max_weight| {
+            ((max_weight - self.weight()).max(0.0) / item.weight()).floor() as u32
+        });
+
+        let in_existing: u32 = self.stacks.iter().filter(|s| s.item == item).map(|s| s.space()).sum();
+        let by_slots = self.max_stacks.map_or(u32::MAX, |max_stacks| {
+            let free_slots = max_stacks.saturating_sub(self.stacks.len()) as u32;
+            in_existing.saturating_add(free_slots.saturating_mul(item.stack_size()))
+        });
+
+        u32::min(by_weight, by_slots)
+    }
+
+    /// Moves as much of `stack` into the container as fits, leaving the rest in `stack`.
+    pub fn insert(&mut self, stack: &mut ItemStack) {
+        let mut to_move = u32::min(stack.available(), self.capacity_for(stack.item));
+        let mut incoming = match stack.split(to_move) {
+            Some(incoming) => incoming,
+            None => return,
+        };
+
+        for existing in self.stacks.iter_mut().filter(|s| s.item == incoming.item) {
+            to_move -= existing.merge(&mut incoming);
+        }
+
+        while to_move > 0 {
+            let new_stack = incoming
+                .split(incoming.item.stack_size())
+                .expect("Remaining items should fit in a new stack.");
+            to_move -= new_stack.count;
+            self.stacks.push(new_stack);
+        }
+    }
+
+    /// Takes up to `count` unreserved items out of the container.
+    pub fn take(&mut self, item: Items, count: u32) -> Option<ItemStack> {
+        let mut taken = ItemStack::new(item, 0);
+        for stack in self.stacks.iter_mut().filter(|s| s.item == item) {
+            if let Some(split) = stack.split(count - taken.count) {
+                taken.count += split.count;
+            }
+            if taken.count == count {
+                break;
+            }
+        }
+        self.remove_empty();
+
+        (!taken.is_empty()).then_some(taken)
+    }
+
+    /// Reserves `count` items for a job, failing without reserving anything if not enough are available.
+    pub fn reserve(&mut self, item: Items, count: u32) -> bool {
+        if self.available(item) < count {
+            return false;
+        }
+
+        let mut remaining = count;
+        for stack in self.stacks.iter_mut().filter(|s| s.item == item) {
+            let reserving = u32::min(remaining, stack.available());
+            stack.reserve(reserving);
+            remaining -= reserving;
+        }
+        true
+    }
+
+    pub fn unreserve(&mut self, item: Items, count: u32) {
+        let mut remaining = count;
+        for stack in self.stacks.iter_mut().filter(|s| s.item == item) {
+            let unreserving = u32::min(remaining, stack.reserved);
+            stack.unreserve(unreserving);
+            remaining -= unreserving;
+        }
+    }
+
+    /// Takes up to `count` previously reserved items out of the container.
+    pub fn take_reserved(&mut self, item: Items, count: u32) -> Option<ItemStack> {
+        let mut taken = ItemStack::new(item, 0);
+        for stack in self.stacks.iter_mut().filter(|s| s.item == item) {
+            if let Some(split) = stack.take_reserved(count - taken.count) {
+                taken.count += split.count;
+            }
+            if taken.count == count {
+                break;
+            }
+        }
+        self.remove_empty();
+
+        (!taken.is_empty()).then_some(taken)
+    }
+
+    /// Empties the container, returning everything that was in it.
+    pub fn drain(&mut self) -> Vec<ItemStack> {
+        std::mem::take(&mut self.stacks)
+    }
+
+    fn remove_empty(&mut self) {
+        self.stacks.retain(|s| !s.is_empty());
+    }
+}
+
+/// The items a villager is carrying around.
+#[derive(Component, Clone, Debug, Deref, DerefMut)]
+pub struct Inventory(pub Container);
+
+impl Inventory {
+    pub fn new(max_weight: f32) -> Self {
+        Self(Container::with_weight(max_weight))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn insert_fills_slots_then_stops() {
+        let mut container = Container::with_slots(2);
+        let mut logs = ItemStack::new(Items::Log, 25);
+
+        container.insert(&mut logs);
+        assert_eq!(container.count(Items::Log), 20);
+        assert_eq!(container.stacks().len(), 2);
+        assert_eq!(logs.count, 5);
+    }
+
+    #[test]
+    fn insert_respects_weight() {
+        let mut container = Container::with_weight(20.0);
+        let mut stones = ItemStack::new(Items::Stone, 5);
+
+        container.insert(&mut stones);
+        assert_eq!(container.count(Items::Stone), 2);
+        assert_eq!(stones.count, 3);
+    }
+
+    #[test]
+    fn reserved_items_can_only_be_taken_as_reserved() {
+        let mut container = Container::with_slots(4);
+        container.insert(&mut ItemStack::new(Items::Log, 15));
+
+        assert!(container.reserve(Items::Log, 12));
+        assert_eq!(container.take(Items::Log, 10).map(|s| s.count), Some(3));
+        assert!(container.take(Items::Log, 1).is_none());
+
+        assert_eq!(container.take_reserved(Items::Log, 12).map(|s| s.count), Some(12));
+        assert!(container.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::{ecs::system::SystemParam, prelude::*};
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::map::tile_xy_world_xy;
+
+use super::ItemStack;
+
+#[derive(SystemParam)]
+pub struct ItemQuery<'w, 's> {
+    commands: Commands<'w, 's>,
+    items: Query<'w, 's, (Entity, &'static TilePos, &'static mut ItemStack)>,
+}
+
+impl<'w, 's> ItemQuery<'w, 's> {
+    /// Drops items on the map, merging them into stacks already lying on the tile where possible.
+    pub fn drop_item(&mut self, mut stack: ItemStack, tile_pos: TilePos) {
+        for (_entity, item_pos, mut existing) in self.items.iter_mut() {
+            if *item_pos == tile_pos {
+                existing.merge(&mut stack);
+            }
+        }
+
+        while let Some(new_stack) = stack.split(stack.item.stack_size()) {
+            self.spawn_stack(new_stack, tile_pos);
+        }
+    }
+
+    pub fn get(&self, item: Entity) -> Option<&ItemStack> {
+        self.items.get(item).ok().map(|(_, _, stack)| stack)
+    }
+
+    /// Takes up to `count` unreserved items from a stack on the map, despawning it once empty.
+    pub fn take(&mut self, item: Entity, count: u32) -> Option<ItemStack> {
+        let (entity, _, mut stack) = self.items.get_mut(item).ok()?;
+        let taken = stack.split(count);
+        if stack.is_empty() {
+            self.commands.entity(entity).despawn_recursive();
+        }
+        taken
+    }
+
+    fn spawn_stack(&mut self, stack: ItemStack, tile_pos: TilePos) {
+        self.commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: stack.item.definition().color,
+                    custom_size: Some(Vec2::splat(6.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(tile_xy_world_xy(tile_pos.x, tile_pos.y).extend(1.5)),
+                ..default()
+            },
+            stack,
+            tile_pos,
+            Name::from(stack.item.name()),
+        ));
+    }
+}
@@ -0,0 +1,129 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use bevy::prelude::*;
+
+use super::Items;
+
+// How often, in seconds, the trend of each item is updated.
+const TREND_INTERVAL: f32 = 10.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ItemLocation {
+    //Sitting in a storage, free to be used
+    Stored,
+    //Sitting in a storage, promised to a job
+    Reserved,
+    //Carried by a villager
+    InTransit,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ItemCounts {
+    pub stored: u32,
+    pub reserved: u32,
+    pub in_transit: u32,
+}
+
+impl ItemCounts {
+    pub fn total(&self) -> u32 {
+        self.stored + self.reserved + self.in_transit
+    }
+
+    fn get_mut(&mut self, location: ItemLocation) -> &mut u32 {
+        match location {
+            ItemLocation::Stored => &mut self.stored,
+            ItemLocation::Reserved => &mut self.reserved,
+            ItemLocation::InTransit => &mut self.in_transit,
+        }
+    }
+}
+
+/// Town-wide count of every item, kept up to date by whoever moves items around.
+#[derive(Resource, Clone, Debug)]
+pub struct TownInventory {
+    counts: HashMap<Items, ItemCounts>,
+    //Totals at the end of the last trend interval
+    last_totals: HashMap<Items, u32>,
+    trends: HashMap<Items, Ordering>,
+    trend_timer: Timer,
+}
+
+impl Default for TownInventory {
+    fn default() -> Self {
+        Self {
+            counts: HashMap::new(),
+            last_totals: HashMap::new(),
+            trends: HashMap::new(),
+            trend_timer: Timer::from_seconds(TREND_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl TownInventory {
+    pub fn counts(&self, item: Items) -> ItemCounts {
+        self.counts.get(&item).copied().unwrap_or_default()
+    }
+
+    pub fn add(&mut self, item: Items, location: ItemLocation, count: u32) {
+        *self.counts.entry(item).or_default().get_mut(location) += count;
+    }
+
+    pub fn remove(&mut self, item: Items, location: ItemLocation, count: u32) {
+        let counts = self.counts.entry(item).or_default().get_mut(location);
+        if *counts < count {
+            warn!(
+                "Removing {} {:?} from the town inventory, but only {} are known.",
+                count, item, counts
+            );
+        }
+        *counts = counts.saturating_sub(count);
+    }
+
+    pub fn transfer(&mut self, item: Items, count: u32, from: ItemLocation, to: ItemLocation) {
+        self.remove(item, from, count);
+        self.add(item, to, count);
+    }
+
+    /// Whether the total of an item grew, shrank or stayed the same over the last trend interval.
+    pub fn trend(&self, item: Items) -> Ordering {
+        self.trends.get(&item).copied().unwrap_or(Ordering::Equal)
+    }
+}
+
+pub fn update_inventory_trends(time: Res<Time>, mut town_inventory: ResMut<TownInventory>) {
+    if town_inventory.trend_timer.tick(time.delta()).just_finished() {
+        for item in Items::ALL {
+            let total = town_inventory.counts(item).total();
+            let last_total = town_inventory.last_totals.insert(item, total).unwrap_or_default();
+            town_inventory.trends.insert(item, total.cmp(&last_total));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn transfer_keeps_total() {
+        let mut inventory = TownInventory::default();
+        inventory.add(Items::Log, ItemLocation::Stored, 5);
+        inventory.transfer(Items::Log, 2, ItemLocation::Stored, ItemLocation::Reserved);
+        inventory.transfer(Items::Log, 2, ItemLocation::Reserved, ItemLocation::InTransit);
+
+        let counts = inventory.counts(Items::Log);
+        assert_eq!(counts.stored, 3);
+        assert_eq!(counts.reserved, 0);
+        assert_eq!(counts.in_transit, 2);
+        assert_eq!(counts.total(), 5);
+    }
+
+    #[test]
+    fn removing_too_many_stops_at_zero() {
+        let mut inventory = TownInventory::default();
+        inventory.add(Items::Stone, ItemLocation::InTransit, 1);
+        inventory.remove(Items::Stone, ItemLocation::InTransit, 3);
+
+        assert_eq!(inventory.counts(Items::Stone).total(), 0);
+    }
+}
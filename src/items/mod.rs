@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    jobs::{job_queue::JobQueue, Jobs},
+    map::{tile_xy_world_xy, Map},
+    states::GameStates,
+    zones::Zone,
+};
+
+mod container;
+mod item_query;
+mod ledger;
+mod registry;
+mod stack;
+
+pub use container::*;
+pub use item_query::ItemQuery;
+pub use ledger::*;
+pub use registry::*;
+pub use stack::ItemStack;
+
+const TOWN_STORAGE_SLOTS: usize = 50;
+
+/// Marks a zone whose container haulers bring dropped items to.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Storage;
+
+pub struct ItemsPlugin;
+
+impl Plugin for ItemsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TownInventory>()
+            .add_enter_system(GameStates::InGame, setup_town_storage)
+            .add_system(queue_haul_jobs.run_in_state(GameStates::InGame))
+            .add_system(update_inventory_trends.run_in_state(GameStates::InGame));
+    }
+}
+
+fn setup_town_storage(mut commands: Commands, map: Res<Map>, storage_query: Query<Entity, With<Storage>>) {
+    if storage_query.is_empty() {
+        let tile_pos = TilePos::new(map.width / 2, map.height / 2);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgb(0.4, 0.25, 0.1),
+                    custom_size: Some(Vec2::splat(14.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(tile_xy_world_xy(tile_pos.x, tile_pos.y).extend(1.5)),
+                ..default()
+            },
+            Storage,
+            Container::with_slots(TOWN_STORAGE_SLOTS),
+            Zone::new(tile_pos, tile_pos),
+            Name::from("Town Storage"),
+        ));
+    }
+}
+
+fn queue_haul_jobs(mut job_queue: ResMut<JobQueue>, dropped_items: Query<(Entity, &TilePos), Added<ItemStack>>) {
+    for (entity, tile_pos) in dropped_items.iter() {
+        job_queue.push(Jobs::Haul(entity), *tile_pos);
+    }
+}
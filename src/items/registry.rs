@@ -0,0 +1,115 @@
+use bevy::prelude::Color;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Items {
+    Log,
+    Stone,
+    Apple,
+    Berry,
+    Wheat,
+    Meat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemCategory {
+    Material,
+    Food,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ItemDefinition {
+    pub name: &'static str,
+    pub category: ItemCategory,
+    //Maximum number of items in a single stack
+    pub stack_size: u32,
+    //Weight of a single item
+    pub weight: f32,
+    //Hunger taken away by eating a single item
+    pub nutrition: f32,
+    //Color used to draw the item on the map
+    pub color: Color,
+}
+
+impl Items {
+    pub const ALL: [Items; 6] = [
+        Items::Log,
+        Items::Stone,
+        Items::Apple,
+        Items::Berry,
+        Items::Wheat,
+        Items::Meat,
+    ];
+
+    pub fn definition(&self) -> ItemDefinition {
+        match self {
+            Items::Log => ItemDefinition {
+                name: "Log",
+                category: ItemCategory::Material,
+                stack_size: 10,
+                weight: 5.0,
+                nutrition: 0.0,
+                color: Color::rgb(0.55, 0.35, 0.17),
+            },
+            Items::Stone => ItemDefinition {
+                name: "Stone",
+                category: ItemCategory::Material,
+                stack_size: 10,
+                weight: 8.0,
+                nutrition: 0.0,
+                color: Color::GRAY,
+            },
+            Items::Apple => ItemDefinition {
+                name: "Apple",
+                category: ItemCategory::Food,
+                stack_size: 20,
+                weight: 0.2,
+                nutrition: 15.0,
+                color: Color::CRIMSON,
+            },
+            Items::Berry => ItemDefinition {
+                name: "Berry",
+                category: ItemCategory::Food,
+                stack_size: 50,
+                weight: 0.1,
+                nutrition: 5.0,
+                color: Color::PURPLE,
+            },
+            Items::Wheat => ItemDefinition {
+                name: "Wheat",
+                category: ItemCategory::Food,
+                stack_size: 30,
+                weight: 0.5,
+                nutrition: 10.0,
+                color: Color::GOLD,
+            },
+            Items::Meat => ItemDefinition {
+                name: "Meat",
+                category: ItemCategory::Food,
+                stack_size: 20,
+                weight: 1.0,
+                nutrition: 25.0,
+                color: Color::rgb(0.7, 0.2, 0.2),
+            },
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.definition().name
+    }
+
+    pub fn category(&self) -> ItemCategory {
+        self.definition().category
+    }
+
+    pub fn stack_size(&self) -> u32 {
+        self.definition().stack_size
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.definition().weight
+    }
+
+    pub fn nutrition(&self) -> f32 {
+        self.definition().nutrition
+    }
+}
@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+
+use super::Items;
+
+/// A pile of identical items, either lying on the map or held in a container.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ItemStack {
+    pub item: Items,
+    pub count: u32,
+    //Items promised to a job, which can't be taken by anyone else
+    pub reserved: u32,
+}
+
+impl ItemStack {
+    pub fn new(item: Items, count: u32) -> Self {
+        Self {
+            item,
+            count,
+            reserved: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    pub fn available(&self) -> u32 {
+        self.count - self.reserved
+    }
+
+    pub fn space(&self) -> u32 {
+        self.item.stack_size().saturating_sub(self.count)
+    }
+
+    pub fn weight(&self) -> f32 {
+        self.count as f32 * self.item.weight()
+    }
+
+    /// Moves as many unreserved items from `other` into this stack as will fit, returning how many were moved.
+    pub fn merge(&mut self, other: &mut ItemStack) -> u32 {
+        if self.item != other.item {
+            return 0;
+        }
+
+        let moved = u32::min(self.space(), other.available());
+        self.count += moved;
+        other.count -= moved;
+        moved
+    }
+
+    /// Splits off up to `count` unreserved items into a new stack.
+    pub fn split(&mut self, count: u32) -> Option<ItemStack> {
+        let taken = u32::min(count, self.available());
+        if taken == 0 {
+            return None;
+        }
+
+        self.count -= taken;
+        Some(ItemStack::new(self.item, taken))
+    }
+
+    /// Reserves `count` items, failing without reserving anything if not enough are available.
+    pub fn reserve(&mut self, count: u32) -> bool {
+        if self.available() < count {
+            return false;
+        }
+
+        self.reserved += count;
+        true
+    }
+
+    pub fn unreserve(&mut self, count: u32) {
+        self.reserved = self.reserved.saturating_sub(count);
+    }
+
+    /// Splits off up to `count` previously reserved items into a new stack.
+    pub fn take_reserved(&mut self, count: u32) -> Option<ItemStack> {
+        let taken = u32::min(count, self.reserved);
+        if taken == 0 {
+            return None;
+        }
+
+        self.reserved -= taken;
+        self.count -= taken;
+        Some(ItemStack::new(self.item, taken))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn merge_respects_stack_size() {
+        let mut stack = ItemStack::new(Items::Log, 8);
+        let mut other = ItemStack::new(Items::Log, 5);
+
+        assert_eq!(stack.merge(&mut other), 2);
+        assert_eq!(stack.count, Items::Log.stack_size());
+        assert_eq!(other.count, 3);
+    }
+
+    #[test]
+    fn merge_ignores_other_items() {
+        let mut stack = ItemStack::new(Items::Log, 1);
+        let mut other = ItemStack::new(Items::Stone, 1);
+
+        assert_eq!(stack.merge(&mut other), 0);
+        assert_eq!(other.count, 1);
+    }
+
+    #[test]
+    fn split_leaves_reserved_items() {
+        let mut stack = ItemStack::new(Items::Apple, 10);
+        assert!(stack.reserve(6));
+        assert!(!stack.reserve(5));
+
+        let split = stack.split(10).expect("Unreserved apples should split off.");
+        assert_eq!(split.count, 4);
+        assert_eq!(stack.count, 6);
+        assert!(stack.split(1).is_none());
+
+        let reserved = stack.take_reserved(6).expect("Reserved apples should be taken.");
+        assert_eq!(reserved.count, 6);
+        assert!(stack.is_empty());
+    }
+}
@@ -5,11 +5,14 @@ use iyes_loopless::state::NextState;
 use leafwing_input_manager::prelude::ActionState;
 
 use crate::{
-    jobs::{job_queue::Job, Jobs},
+    ai::wildlife::{Animal, Hunted},
+    jobs::Jobs,
     map::{
-        components::{Choppable, Mineable},
-        world_xy_tile_xy, FeatureLayer, Features,
+        components::{Choppable, Harvestable, Mineable},
+        world_xy_tile_xy, FeatureLayer, Features, Map,
     },
+    tech::Research,
+    zones::{spawn_farm, spawn_stockpile, Zone},
 };
 
 use super::{job_queue::*, JobCreation, JobCreationControls, JobCreationMenuManager, JobSelectionType};
@@ -22,11 +25,15 @@ pub fn handle_job_creation_hotkeys(
     job_type: Res<JobSelectionType>,
     selection: Option<Res<SelectionStart>>,
     mut job_queue: ResMut<JobQueue>,
+    map: Res<Map>,
+    research: Res<Research>,
     query: Query<&ActionState<JobCreationControls>, With<JobCreationMenuManager>>,
     mouse_pos: Res<MousePosWorld>,
     feature_tiles_query: Query<&TileStorage, With<FeatureLayer>>,
     choppable_tiles_query: Query<Entity, With<Choppable>>,
     mineable_tiles_query: Query<Entity, With<Mineable>>,
+    harvestable_tiles_query: Query<Entity, With<Harvestable>>,
+    animals_query: Query<(Entity, &Transform), (With<Animal>, Without<Hunted>)>,
 ) {
     let job_creation_menu = query.single();
 
@@ -43,7 +50,7 @@ pub fn handle_job_creation_hotkeys(
                             let entity = feature_tile_storage.get(&tile_pos);
                             if let Some(entity) = entity {
                                 if choppable_tiles_query.contains(entity) {
-                                    job_queue.jobs.push_back(Job::new(Jobs::Chop, tile_pos));
+                                    job_queue.push(Jobs::Chop, tile_pos);
                                 }
                             }
                         }
@@ -56,7 +63,42 @@ pub fn handle_job_creation_hotkeys(
                             let entity = feature_tile_storage.get(&tile_pos);
                             if let Some(entity) = entity {
                                 if mineable_tiles_query.contains(entity) {
-                                    job_queue.jobs.push_back(Job::new(Jobs::Mine, tile_pos));
+                                    job_queue.push(Jobs::Mine, tile_pos);
+                                }
+                            }
+                        }
+                    }
+                }
+                JobCreation::Harvest => {
+                    for x in u32::min(selection.x, world_tile.x)..=u32::max(selection.x, world_tile.x) {
+                        for y in u32::min(selection.y, world_tile.y)..=u32::max(selection.y, world_tile.y) {
+                            let tile_pos = TilePos::new(x, y);
+                            let entity = feature_tile_storage.get(&tile_pos);
+                            if let Some(entity) = entity {
+                                if harvestable_tiles_query.contains(entity) {
+                                    job_queue.push(Jobs::Harvest, tile_pos);
+                                }
+                            }
+                        }
+                    }
+                }
+                JobCreation::Hunt => {
+                    let zone = Zone::new(**selection, world_tile);
+                    for (animal, transform) in animals_query.iter() {
+                        let tile_pos = world_xy_tile_xy(transform.translation.xy());
+                        if zone.contains(&tile_pos) {
+                            job_queue.push(Jobs::Hunt(animal), tile_pos);
+                            commands.entity(animal).insert(Hunted);
+                        }
+                    }
+                }
+                JobCreation::Deconstruct => {
+                    for x in u32::min(selection.x, world_tile.x)..=u32::max(selection.x, world_tile.x) {
+                        for y in u32::min(selection.y, world_tile.y)..=u32::max(selection.y, world_tile.y) {
+                            let tile_pos = TilePos::new(x, y);
+                            if let Some(feature) = map.features[map.tile_xy_idx(x, y)] {
+                                if feature.is_player_built() {
+                                    job_queue.push(Jobs::Deconstruct, tile_pos);
                                 }
                             }
                         }
@@ -67,10 +109,24 @@ pub fn handle_job_creation_hotkeys(
                     for x in u32::min(selection.x, world_tile.x)..=u32::max(selection.x, world_tile.x) {
                         for y in u32::min(selection.y, world_tile.y)..=u32::max(selection.y, world_tile.y) {
                             let tile_pos = TilePos::new(x, y);
-                            let entity = feature_tile_storage.get(&tile_pos);
-                            if entity.is_none() {
-                                job_queue.jobs.push_back(Job::new(Jobs::Build(feature), tile_pos));
+                            if map.features[map.tile_xy_idx(x, y)] == Some(feature) {
+                                continue;
                             }
+
+                            let prerequisite = queue_prerequisite(
+                                &mut job_queue,
+                                tile_pos,
+                                map.features[map.tile_xy_idx(x, y)],
+                                feature_tile_storage.get(&tile_pos),
+                                &choppable_tiles_query,
+                                &mineable_tiles_query,
+                            );
+                            job_queue.push_with_materials(
+                                Jobs::Build(feature),
+                                tile_pos,
+                                &prerequisite,
+                                research.recipe(feature),
+                            );
                         }
                     }
                 }
@@ -78,6 +134,8 @@ pub fn handle_job_creation_hotkeys(
                 // Manually specify door, shift size, add furniture
                 // All implemented using phantom tiles
                 JobCreation::BuildRoom => {
+                    let mut walls = Vec::new();
+                    let mut interior = Vec::new();
                     let i_max = (u32::max(selection.x, world_tile.x) - u32::min(selection.x, world_tile.x)) as usize;
                     let j_max = (u32::max(selection.y, world_tile.y) - u32::min(selection.y, world_tile.y)) as usize;
                     for (i, x) in
@@ -87,20 +145,50 @@ pub fn handle_job_creation_hotkeys(
                             (u32::min(selection.y, world_tile.y)..=u32::max(selection.y, world_tile.y)).enumerate()
                         {
                             let tile_pos = TilePos::new(x, y);
-                            let entity = feature_tile_storage.get(&tile_pos);
-                            if entity.is_none() {
-                                // Hack to add a door.
-                                let feature = if i == 0 && j == 1 {
-                                    Features::Door
-                                } else if i == 0 || j == 0 || i == i_max || j == j_max {
-                                    Features::Wall
-                                } else {
-                                    Features::Floor
-                                };
-                                job_queue.jobs.push_back(Job::new(Jobs::Build(feature), tile_pos));
+                            // Hack to add a door.
+                            let feature = if i == 0 && j == 1 {
+                                Features::Door
+                            } else if i == 0 || j == 0 || i == i_max || j == j_max {
+                                Features::Wall
+                            } else {
+                                Features::Floor
+                            };
+                            if map.features[map.tile_xy_idx(x, y)] == Some(feature) {
+                                continue;
+                            }
+
+                            let prerequisite = queue_prerequisite(
+                                &mut job_queue,
+                                tile_pos,
+                                map.features[map.tile_xy_idx(x, y)],
+                                feature_tile_storage.get(&tile_pos),
+                                &choppable_tiles_query,
+                                &mineable_tiles_query,
+                            );
+                            if feature == Features::Wall {
+                                let wall = job_queue.push_with_materials(
+                                    Jobs::Build(feature),
+                                    tile_pos,
+                                    &prerequisite,
+                                    research.recipe(feature),
+                                );
+                                walls.push(wall);
+                            } else {
+                                interior.push((feature, tile_pos, prerequisite));
                             }
                         }
                     }
+
+                    // Doors and floors are only built once the walls holding them up are finished.
+                    for (feature, tile_pos, mut dependencies) in interior {
+                        dependencies.extend_from_slice(&walls);
+                        job_queue.push_with_materials(
+                            Jobs::Build(feature),
+                            tile_pos,
+                            &dependencies,
+                            research.recipe(feature),
+                        );
+                    }
                 }
                 JobCreation::Clear => {
                     for x in u32::min(selection.x, world_tile.x)..=u32::max(selection.x, world_tile.x) {
@@ -108,11 +196,38 @@ pub fn handle_job_creation_hotkeys(
                             let tile_pos = TilePos::new(x, y);
                             let entity = feature_tile_storage.get(&tile_pos);
                             if entity.is_some() {
-                                job_queue.jobs.push_back(Job::new(Jobs::Clear, tile_pos));
+                                job_queue.push(Jobs::Clear, tile_pos);
                             }
                         }
                     }
                 }
+                JobCreation::Stockpile(filter) => {
+                    spawn_stockpile(&mut commands, Zone::new(**selection, world_tile), filter);
+                }
+                JobCreation::Farm => {
+                    let zone = Zone::new(**selection, world_tile);
+                    for tile_pos in zone.tiles() {
+                        let idx = map.tile_xy_idx(tile_pos.x, tile_pos.y);
+                        let already_farmed = map.features[idx]
+                            .map_or(false, |feature| feature == Features::Farmland || feature.is_crop());
+                        //Farms are laid out around buildings, not through them.
+                        let built_on = map.features[idx].map_or(false, |feature| feature.is_player_built());
+                        if map.tiles[idx].fertility() <= 0.0 || already_farmed || built_on {
+                            continue;
+                        }
+
+                        let prerequisite = queue_prerequisite(
+                            &mut job_queue,
+                            tile_pos,
+                            map.features[idx],
+                            feature_tile_storage.get(&tile_pos),
+                            &choppable_tiles_query,
+                            &mineable_tiles_query,
+                        );
+                        job_queue.push_with_dependencies(Jobs::Till, tile_pos, &prerequisite);
+                    }
+                    spawn_farm(&mut commands, zone);
+                }
             }
             commands.remove_resource::<SelectionStart>();
             commands.insert_resource(NextState(crate::states::GameStates::InGame));
@@ -121,3 +236,41 @@ pub fn handle_job_creation_hotkeys(
         }
     }
 }
+
+/// Queues the jobs needed to empty an occupied tile, returning the job that leaves it empty.
+/// Jobs already ordered on the tile are reused rather than queued twice.
+fn queue_prerequisite(
+    job_queue: &mut JobQueue,
+    tile_pos: TilePos,
+    feature: Option<Features>,
+    feature_entity: Option<Entity>,
+    choppable_tiles_query: &Query<Entity, With<Choppable>>,
+    mineable_tiles_query: &Query<Entity, With<Mineable>>,
+) -> Vec<JobId> {
+    let feature_entity = match feature_entity {
+        Some(feature_entity) => feature_entity,
+        None => return Vec::new(),
+    };
+
+    let last = if choppable_tiles_query.contains(feature_entity) {
+        // Chopping leaves a stump behind, which still needs to be cleared.
+        let chop = unfinished_or_push(job_queue, Jobs::Chop, tile_pos, &[]);
+        unfinished_or_push(job_queue, Jobs::Clear, tile_pos, &[chop])
+    } else if mineable_tiles_query.contains(feature_entity) {
+        unfinished_or_push(job_queue, Jobs::Mine, tile_pos, &[])
+    } else if feature.map_or(false, |feature| feature.is_player_built()) {
+        // Buildings are taken down properly, so their materials are refunded.
+        unfinished_or_push(job_queue, Jobs::Deconstruct, tile_pos, &[])
+    } else {
+        unfinished_or_push(job_queue, Jobs::Clear, tile_pos, &[])
+    };
+
+    vec![last]
+}
+
+fn unfinished_or_push(job_queue: &mut JobQueue, job_type: Jobs, tile_pos: TilePos, dependencies: &[JobId]) -> JobId {
+    match job_queue.unfinished(job_type, tile_pos) {
+        Some(id) => id,
+        None => job_queue.push_with_dependencies(job_type, tile_pos, dependencies),
+    }
+}
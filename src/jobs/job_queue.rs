@@ -1,22 +1,284 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use super::Jobs;
-use bevy::prelude::Resource;
+use super::{JobCategory, Jobs};
+use crate::items::ItemStack;
+use bevy::prelude::{Entity, Resource};
 use bevy_ecs_tilemap::prelude::*;
 
+// How many of the oldest available jobs a worker considers when choosing what to do next.
+const JOB_SELECTION_WINDOW: usize = 10;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct JobId(u64);
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Job {
+    pub id: JobId,
     pub job_type: Jobs,
     pub position: TilePos,
+    //Materials that have to be brought to the job site, fixed when the job is queued
+    pub materials: Option<ItemStack>,
 }
 
 impl Job {
-    pub fn new(job_type: Jobs, position: TilePos) -> Self {
-        Self { job_type, position }
+    fn new(id: JobId, job_type: Jobs, position: TilePos, materials: Option<ItemStack>) -> Self {
+        Self {
+            id,
+            job_type,
+            position,
+            materials,
+        }
     }
 }
 
 #[derive(Resource, Debug, Clone, Default)]
 pub struct JobQueue {
     pub jobs: VecDeque<Job>,
+    // Jobs that still need to be done before the keyed job can be offered.
+    dependencies: HashMap<JobId, Vec<JobId>>,
+    // Jobs that are either waiting in the queue or being worked on, with what they are and where.
+    unfinished: HashMap<JobId, (Jobs, TilePos)>,
+    // Jobs that need materials nobody has set aside for them yet.
+    awaiting_materials: HashSet<JobId>,
+    // Storages holding the materials reserved for a job.
+    material_sources: HashMap<JobId, Entity>,
+    next_id: u64,
+}
+
+impl JobQueue {
+    pub fn push(&mut self, job_type: Jobs, position: TilePos) -> JobId {
+        self.push_with_dependencies(job_type, position, &[])
+    }
+
+    pub fn push_with_dependencies(&mut self, job_type: Jobs, position: TilePos, dependencies: &[JobId]) -> JobId {
+        self.push_with_materials(job_type, position, dependencies, job_type.materials())
+    }
+
+    /// Queues a job that needs different materials than its type usually does.
+    pub fn push_with_materials(
+        &mut self,
+        job_type: Jobs,
+        position: TilePos,
+        dependencies: &[JobId],
+        materials: Option<ItemStack>,
+    ) -> JobId {
+        let id = JobId(self.next_id);
+        self.next_id += 1;
+
+        let dependencies: Vec<JobId> = dependencies
+            .iter()
+            .copied()
+            .filter(|dependency| self.unfinished.contains_key(dependency))
+            .collect();
+        if !dependencies.is_empty() {
+            self.dependencies.insert(id, dependencies);
+        }
+
+        if materials.is_some() {
+            self.awaiting_materials.insert(id);
+        }
+
+        self.unfinished.insert(id, (job_type, position));
+        self.jobs.push_back(Job::new(id, job_type, position, materials));
+        id
+    }
+
+    pub fn is_available(&self, job: &Job) -> bool {
+        !self.awaiting_materials.contains(&job.id)
+            && self.dependencies.get(&job.id).map_or(true, |dependencies| {
+                dependencies.iter().all(|d| !self.unfinished.contains_key(d))
+            })
+    }
+
+    pub fn has_available(&self, category: JobCategory) -> bool {
+        self.jobs
+            .iter()
+            .any(|job| job.job_type.category() == category && self.is_available(job))
+    }
+
+    /// Removes and returns the first job whose dependencies have all been completed.
+    pub fn take_available(&mut self) -> Option<Job> {
+        let idx = self.jobs.iter().position(|job| self.is_available(job))?;
+        self.jobs.remove(idx)
+    }
+
+    /// Removes and returns the best suited of the oldest available jobs of a category according to `preference`.
+    pub fn take_preferred(&mut self, category: JobCategory, preference: impl Fn(&Job) -> f32) -> Option<Job> {
+        let idx = self
+            .jobs
+            .iter()
+            .enumerate()
+            .filter(|(_, job)| job.job_type.category() == category && self.is_available(job))
+            .take(JOB_SELECTION_WINDOW)
+            .fold(None, |best: Option<(usize, f32)>, (idx, job)| {
+                let score = preference(job);
+                match best {
+                    Some((_, best_score)) if best_score >= score => best,
+                    _ => Some((idx, score)),
+                }
+            })
+            .map(|(idx, _)| idx)?;
+        self.jobs.remove(idx)
+    }
+
+    /// A job of the same type already ordered at the position, whether it's queued or being worked on.
+    pub fn unfinished(&self, job_type: Jobs, position: TilePos) -> Option<JobId> {
+        self.unfinished
+            .iter()
+            .find(|(_, unfinished)| **unfinished == (job_type, position))
+            .map(|(id, _)| *id)
+    }
+
+    /// Jobs still in the queue that can't be offered until their materials are reserved.
+    pub fn awaiting_materials(&self) -> impl Iterator<Item = &Job> {
+        self.jobs.iter().filter(|job| self.awaiting_materials.contains(&job.id))
+    }
+
+    /// Records that the materials for a job have been reserved in `source`, making the job available.
+    pub fn fund(&mut self, id: JobId, source: Entity) {
+        self.awaiting_materials.remove(&id);
+        self.material_sources.insert(id, source);
+    }
+
+    pub fn material_source(&self, id: JobId) -> Option<Entity> {
+        self.material_sources.get(&id).copied()
+    }
+
+    /// Puts a job that couldn't be finished back in the queue, keeping its id and dependencies.
+    /// Its materials have to be set aside again before it's offered.
+    pub fn requeue(&mut self, job: Job) {
+        self.material_sources.remove(&job.id);
+        if job.materials.is_some() {
+            self.awaiting_materials.insert(job.id);
+        }
+        self.jobs.push_back(job);
+    }
+
+    /// Marks a job as done, unlocking any job that depended on it.
+    pub fn complete(&mut self, id: JobId) {
+        self.unfinished.remove(&id);
+        self.dependencies.remove(&id);
+        self.awaiting_materials.remove(&id);
+        self.material_sources.remove(&id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map::Features;
+
+    fn push_funded(queue: &mut JobQueue, job_type: Jobs, position: TilePos, dependencies: &[JobId]) -> JobId {
+        let id = queue.push_with_dependencies(job_type, position, dependencies);
+        queue.fund(id, Entity::from_raw(0));
+        id
+    }
+
+    #[test]
+    fn dependent_job_waits_for_prerequisite() {
+        let mut queue = JobQueue::default();
+        let chop = queue.push(Jobs::Chop, TilePos::new(1, 1));
+        let build = push_funded(&mut queue, Jobs::Build(Features::Wall), TilePos::new(1, 1), &[chop]);
+
+        let first = queue.take_available().expect("Chop should be available.");
+        assert_eq!(first.id, chop);
+        assert!(!queue.has_available(JobCategory::Work));
+        assert!(queue.take_available().is_none());
+
+        queue.complete(chop);
+        let second = queue.take_available().expect("Build should be available.");
+        assert_eq!(second.id, build);
+    }
+
+    #[test]
+    fn available_jobs_skip_blocked_ones() {
+        let mut queue = JobQueue::default();
+        let wall = push_funded(&mut queue, Jobs::Build(Features::Wall), TilePos::new(0, 0), &[]);
+        push_funded(&mut queue, Jobs::Build(Features::Floor), TilePos::new(1, 1), &[wall]);
+        let road = push_funded(&mut queue, Jobs::Build(Features::Road), TilePos::new(5, 5), &[]);
+
+        assert_eq!(queue.take_available().map(|job| job.id), Some(wall));
+        assert_eq!(queue.take_available().map(|job| job.id), Some(road));
+        assert!(queue.take_available().is_none());
+    }
+
+    #[test]
+    fn preferred_job_is_taken_first() {
+        let mut queue = JobQueue::default();
+        queue.push(Jobs::Chop, TilePos::new(0, 0));
+        let mine = queue.push(Jobs::Mine, TilePos::new(1, 0));
+
+        let job = queue.take_preferred(
+            JobCategory::Work,
+            |job| {
+                if job.job_type == Jobs::Mine {
+                    1.0
+                } else {
+                    0.0
+                }
+            },
+        );
+        assert_eq!(job.map(|job| job.id), Some(mine));
+    }
+
+    #[test]
+    fn completed_dependencies_are_ignored() {
+        let mut queue = JobQueue::default();
+        let clear = queue.push(Jobs::Clear, TilePos::new(2, 2));
+        queue.take_available();
+        queue.complete(clear);
+
+        let build = push_funded(&mut queue, Jobs::Build(Features::Road), TilePos::new(2, 2), &[clear]);
+        assert_eq!(queue.take_available().map(|job| job.id), Some(build));
+    }
+
+    #[test]
+    fn build_job_waits_for_materials() {
+        let mut queue = JobQueue::default();
+        let build = queue.push(Jobs::Build(Features::Wall), TilePos::new(3, 3));
+        assert!(queue.take_available().is_none());
+        assert_eq!(
+            queue.awaiting_materials().map(|job| job.id).collect::<Vec<_>>(),
+            vec![build]
+        );
+
+        let storage = Entity::from_raw(7);
+        queue.fund(build, storage);
+        assert_eq!(queue.material_source(build), Some(storage));
+        assert_eq!(queue.take_available().map(|job| job.id), Some(build));
+    }
+
+    #[test]
+    fn unfinished_jobs_are_found_until_completed() {
+        let mut queue = JobQueue::default();
+        let chop = queue.push(Jobs::Chop, TilePos::new(6, 6));
+        assert_eq!(queue.unfinished(Jobs::Chop, TilePos::new(6, 6)), Some(chop));
+        assert_eq!(queue.unfinished(Jobs::Clear, TilePos::new(6, 6)), None);
+        assert_eq!(queue.unfinished(Jobs::Chop, TilePos::new(6, 7)), None);
+
+        queue.take_available();
+        assert_eq!(queue.unfinished(Jobs::Chop, TilePos::new(6, 6)), Some(chop));
+        queue.complete(chop);
+        assert_eq!(queue.unfinished(Jobs::Chop, TilePos::new(6, 6)), None);
+    }
+
+    #[test]
+    fn requeued_job_keeps_blocking_dependents() {
+        let mut queue = JobQueue::default();
+        let wall = push_funded(&mut queue, Jobs::Build(Features::Wall), TilePos::new(4, 4), &[]);
+        let floor = push_funded(&mut queue, Jobs::Build(Features::Floor), TilePos::new(4, 5), &[wall]);
+
+        let job = queue.take_available().expect("Wall should be available.");
+        queue.requeue(job);
+        assert!(queue.take_available().is_none());
+        assert_eq!(
+            queue.awaiting_materials().map(|job| job.id).collect::<Vec<_>>(),
+            vec![wall]
+        );
+
+        queue.fund(wall, Entity::from_raw(1));
+        assert_eq!(queue.take_available().map(|job| job.id), Some(wall));
+        queue.complete(wall);
+        assert_eq!(queue.take_available().map(|job| job.id), Some(floor));
+    }
 }
@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    items::{Container, ItemLocation, Storage, TownInventory},
+    map::tile_xy_world_xy,
+    zones::Zone,
+};
+
+use super::job_queue::{JobId, JobQueue};
+
+const MISSING_MATERIALS_COLOR: Color = Color::rgba(0.9, 0.1, 0.1, 0.4);
+
+/// Shown over the site of a job that is waiting for materials.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MissingMaterials(JobId);
+
+/// Reserves materials for waiting jobs in the closest storage that has enough of them.
+pub fn fund_jobs(
+    mut job_queue: ResMut<JobQueue>,
+    mut town_inventory: ResMut<TownInventory>,
+    mut storages: Query<(Entity, &Zone, &mut Container), With<Storage>>,
+) {
+    let waiting: Vec<_> = job_queue.awaiting_materials().copied().collect();
+    for job in waiting {
+        let materials = match job.materials {
+            Some(materials) => materials,
+            None => continue,
+        };
+
+        let source = storages
+            .iter_mut()
+            .filter(|(_, _, container)| container.available(materials.item) >= materials.count)
+            .min_by_key(|(_, zone, _)| {
+                let closest = zone.closest_tile(&job.position);
+                closest.x.abs_diff(job.position.x) + closest.y.abs_diff(job.position.y)
+            });
+        if let Some((storage, _, mut container)) = source {
+            if container.reserve(materials.item, materials.count) {
+                town_inventory.transfer(
+                    materials.item,
+                    materials.count,
+                    ItemLocation::Stored,
+                    ItemLocation::Reserved,
+                );
+                job_queue.fund(job.id, storage);
+            }
+        }
+    }
+}
+
+pub fn update_missing_materials_markers(
+    mut commands: Commands,
+    job_queue: Res<JobQueue>,
+    markers: Query<(Entity, &MissingMaterials)>,
+) {
+    let mut waiting: HashSet<JobId> = job_queue.awaiting_materials().map(|job| job.id).collect();
+    for (entity, marker) in markers.iter() {
+        //Markers that are still needed are removed from the set so they aren't spawned twice.
+        if !waiting.remove(&marker.0) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for job in job_queue.awaiting_materials().filter(|job| waiting.contains(&job.id)) {
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: MISSING_MATERIALS_COLOR,
+                    custom_size: Some(Vec2::splat(6.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(
+                    (tile_xy_world_xy(job.position.x, job.position.y) + Vec2::new(4.0, 4.0)).extend(1.6),
+                ),
+                ..default()
+            },
+            MissingMaterials(job.id),
+            Name::from("Missing Materials"),
+        ));
+    }
+}
@@ -2,22 +2,39 @@ use bevy::prelude::*;
 use bevy_asset_loader::prelude::*;
 use iyes_loopless::prelude::*;
 use leafwing_input_manager::prelude::*;
+use serde::Deserialize;
 
 mod cursor;
 mod job_creation;
 pub mod job_queue;
+mod materials;
 
 pub use job_creation::SelectionStart;
 use job_queue::*;
 
-use crate::{cleanup_entity_by_component, cleanup_resource, map::Features, states::GameStates};
+use crate::{
+    ai::characteristics::Skill, cleanup_entity_by_component, cleanup_resource, items::ItemStack, map::Features,
+    states::GameStates, zones::ItemFilter,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Jobs {
     Chop,
     Mine,
     Clear,
+    Harvest,
+    Deconstruct,
     Build(Features),
+    Haul(Entity),
+    Till,
+    Sow,
+    Hunt(Entity),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
+pub enum JobCategory {
+    Work,
+    Haul,
 }
 
 impl Jobs {
@@ -27,6 +44,39 @@ impl Jobs {
             Jobs::Mine => 15.0,
             Jobs::Build(_) => 30.0,
             Jobs::Clear => 10.0,
+            Jobs::Harvest => 25.0,
+            Jobs::Deconstruct => 8.0,
+            Jobs::Haul(_) => 50.0,
+            Jobs::Till => 15.0,
+            Jobs::Sow => 25.0,
+            Jobs::Hunt(_) => 25.0,
+        }
+    }
+
+    pub fn skill(&self) -> Option<Skill> {
+        match self {
+            Jobs::Chop => Some(Skill::Chopping),
+            Jobs::Mine => Some(Skill::Mining),
+            Jobs::Build(_) | Jobs::Deconstruct => Some(Skill::Building),
+            Jobs::Clear => Some(Skill::Clearing),
+            Jobs::Till | Jobs::Sow => Some(Skill::Farming),
+            Jobs::Hunt(_) => Some(Skill::Hunting),
+            Jobs::Harvest | Jobs::Haul(_) => None,
+        }
+    }
+
+    /// Materials that have to be brought to the job site before the job can be done.
+    pub fn materials(&self) -> Option<ItemStack> {
+        match self {
+            Jobs::Build(feature) => feature.recipe(),
+            _ => None,
+        }
+    }
+
+    pub fn category(&self) -> JobCategory {
+        match self {
+            Jobs::Haul(_) => JobCategory::Haul,
+            _ => JobCategory::Work,
         }
     }
 }
@@ -36,8 +86,13 @@ pub enum JobCreation {
     Chop,
     Mine,
     Clear,
+    Harvest,
+    Deconstruct,
     Build(Features),
     BuildRoom,
+    Stockpile(ItemFilter),
+    Farm,
+    Hunt,
 }
 
 #[derive(Resource, Clone, Copy, Debug, Deref)]
@@ -63,6 +118,14 @@ impl Plugin for JobsPlugin {
             .add_plugin(InputManagerPlugin::<JobCreationControls>::default())
             .add_enter_system(GameStates::InGame, setup_job_manager);
 
+        app.add_system_set(
+            ConditionSet::new()
+                .run_in_state(GameStates::InGame)
+                .with_system(materials::fund_jobs)
+                .with_system(materials::update_missing_materials_markers)
+                .into(),
+        );
+
         app.add_system_set(
             ConditionSet::new()
                 .run_in_state(GameStates::InJobSelection)
@@ -21,10 +21,14 @@ mod ai;
 mod animation;
 mod camera;
 mod gui;
+mod items;
 pub mod jobs;
+mod logistics;
 mod map;
 mod simulation;
 pub mod states;
+mod tech;
+mod zones;
 
 pub fn app() -> App {
     use states::GameStates;
@@ -77,7 +81,10 @@ pub fn app() -> App {
                 .with_collection::<map::TilemapAssets>()
                 .with_collection::<animation::SpriteAssets>()
                 .with_collection::<jobs::JobCreationMenuAssets>()
-                .with_collection::<gui::UiAssets>(),
+                .with_collection::<gui::UiAssets>()
+                .with_collection::<ai::BehaviourAssets>()
+                .with_collection::<tech::TechAssets>()
+                .init_resource::<tech::Research>(),
         )
         .add_plugin(ProgressPlugin::new(GameStates::Splash));
 
@@ -87,8 +94,11 @@ pub fn app() -> App {
     app.add_plugin(camera::CameraPlugin)
         .add_plugin(map::MapGenPlugin)
         .add_plugin(jobs::JobsPlugin)
+        .add_plugin(items::ItemsPlugin)
         .add_plugin(ai::AIPlugin)
         .add_plugin(simulation::SimulationPlugin)
+        .add_plugin(logistics::LogisticsPlugin)
+        .add_plugin(tech::TechPlugin)
         .add_plugin(animation::AnimationPlugin)
         .add_plugin(gui::GuiPlugin);
 
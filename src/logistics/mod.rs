@@ -0,0 +1,142 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::{
+    items::Storage,
+    map::{
+        components::{Bed, WaterSource},
+        tile_xy_world_xy, Map, TILE_SIZE,
+    },
+    states::GameStates,
+    zones::Zone,
+};
+
+mod reach;
+
+use reach::travel_costs;
+
+// How often, in seconds, the logistic reach of the town is recomputed.
+const REACH_INTERVAL: f32 = 5.0;
+// Tiles further than this from a water source, stockpile or home are out of reach.
+const MAX_TRAVEL_COST: isize = 4000;
+// Round trip to all three at the furthest reachable tile.
+const MAX_ROUND_TRIP: isize = 2 * 3 * MAX_TRAVEL_COST;
+// Summed reach score one villager needs to be comfortably supplied.
+const REACH_PER_VILLAGER: f32 = 25.0;
+
+/// How well every tile of the map is served by the town's water sources, stockpiles and homes.
+#[derive(Resource, Clone, Debug)]
+pub struct LogisticReach {
+    //Score between 0 and 1 for every tile, 0 when any of the three is out of reach
+    scores: Vec<f32>,
+    timer: Timer,
+}
+
+impl Default for LogisticReach {
+    fn default() -> Self {
+        Self {
+            scores: Vec::new(),
+            timer: Timer::from_seconds(REACH_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+impl LogisticReach {
+    /// Rough number of villagers the town can supply with its current layout.
+    pub fn capacity(&self) -> u32 {
+        (self.scores.iter().sum::<f32>() / REACH_PER_VILLAGER) as u32
+    }
+}
+
+/// Whether the logistic reach heatmap is drawn over the map.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ReachOverlay {
+    pub visible: bool,
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ReachOverlayTile;
+
+pub struct LogisticsPlugin;
+
+impl Plugin for LogisticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogisticReach>()
+            .init_resource::<ReachOverlay>()
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameStates::InGame)
+                    .with_system(update_logistic_reach)
+                    .with_system(update_reach_overlay)
+                    .into(),
+            );
+    }
+}
+
+fn update_logistic_reach(
+    time: Res<Time>,
+    map: Res<Map>,
+    mut reach: ResMut<LogisticReach>,
+    water_sources: Query<&TilePos, With<WaterSource>>,
+    storages: Query<&Zone, With<Storage>>,
+    beds: Query<&TilePos, With<Bed>>,
+) {
+    //Ticking the timer alone shouldn't redraw the overlay.
+    if !reach.bypass_change_detection().timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let water = travel_costs(&map, water_sources.iter().copied(), MAX_TRAVEL_COST);
+    let stockpile = travel_costs(&map, storages.iter().flat_map(|zone| zone.tiles()), MAX_TRAVEL_COST);
+    let home = travel_costs(&map, beds.iter().copied(), MAX_TRAVEL_COST);
+
+    reach.scores = water
+        .iter()
+        .zip(stockpile.iter())
+        .zip(home.iter())
+        .map(|((water, stockpile), home)| match (water, stockpile, home) {
+            (Some(water), Some(stockpile), Some(home)) => {
+                let round_trip = 2 * (water + stockpile + home);
+                1.0 - round_trip as f32 / MAX_ROUND_TRIP as f32
+            }
+            _ => 0.0,
+        })
+        .collect();
+}
+
+fn update_reach_overlay(
+    mut commands: Commands,
+    map: Res<Map>,
+    reach: Res<LogisticReach>,
+    overlay: Res<ReachOverlay>,
+    overlay_tiles: Query<Entity, With<ReachOverlayTile>>,
+) {
+    if !reach.is_changed() && !overlay.is_changed() {
+        return;
+    }
+
+    for entity in overlay_tiles.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    if !overlay.visible {
+        return;
+    }
+
+    for (idx, score) in reach.scores.iter().enumerate().filter(|(_, score)| **score > 0.0) {
+        let tile_pos = map.idx_tile_xy(idx);
+        commands.spawn((
+            SpriteBundle {
+                sprite: Sprite {
+                    color: Color::rgba(1.0 - score, *score, 0.0, 0.35),
+                    custom_size: Some(Vec2::new(TILE_SIZE.x, TILE_SIZE.y)),
+                    ..default()
+                },
+                transform: Transform::from_translation(tile_xy_world_xy(tile_pos.x, tile_pos.y).extend(1.3)),
+                ..default()
+            },
+            ReachOverlayTile,
+        ));
+    }
+}
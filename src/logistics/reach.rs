@@ -0,0 +1,78 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use bevy_ecs_tilemap::tiles::TilePos;
+use hierarchical_pathfinding::prelude::Neighborhood;
+
+use crate::map::Map;
+
+/// Travel cost from every tile to the closest of the sources, using the same costs as pathfinding.
+///
+/// Tiles that can't be reached, or that are more than `max_cost` away, are left as `None`.
+pub fn travel_costs(map: &Map, sources: impl IntoIterator<Item = TilePos>, max_cost: isize) -> Vec<Option<isize>> {
+    let mut costs = vec![None; map.tiles.len()];
+    let mut open = BinaryHeap::new();
+    for source in sources {
+        let idx = map.tile_xy_idx(source.x, source.y);
+        //Sources can be obstacles themselves, like wells, so they are seeded without checking their cost.
+        if costs[idx].is_none() {
+            costs[idx] = Some(0);
+            open.push(Reverse((0, idx)));
+        }
+    }
+
+    let mut neighbors = Vec::with_capacity(8);
+    while let Some(Reverse((cost, idx))) = open.pop() {
+        if costs[idx].map_or(false, |known| known < cost) {
+            continue;
+        }
+
+        let tile = map.idx_tile_xy(idx);
+        neighbors.clear();
+        map.neighborhood
+            .get_all_neighbors((tile.x as usize, tile.y as usize), &mut neighbors);
+        for &(x, y) in neighbors.iter() {
+            let step = map.tile_cost(x as u32, y as u32);
+            if step < 0 {
+                continue;
+            }
+
+            let neighbor_cost = cost + step;
+            let neighbor_idx = map.tile_xy_idx(x as u32, y as u32);
+            if neighbor_cost <= max_cost && costs[neighbor_idx].map_or(true, |known| neighbor_cost < known) {
+                costs[neighbor_idx] = Some(neighbor_cost);
+                open.push(Reverse((neighbor_cost, neighbor_idx)));
+            }
+        }
+    }
+    costs
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::map::{Biomes, Features};
+
+    fn grassland(width: u32, height: u32) -> Map {
+        let mut map = Map::new(height, width);
+        map.tiles.fill(Biomes::Grassland);
+        map
+    }
+
+    #[test]
+    fn costs_grow_with_distance() {
+        let map = grassland(5, 1);
+        let costs = travel_costs(&map, [TilePos::new(0, 0)], 1000);
+
+        assert_eq!(costs, vec![Some(0), Some(100), Some(200), Some(300), Some(400)]);
+    }
+
+    #[test]
+    fn obstacles_and_max_cost_cut_off_tiles() {
+        let mut map = grassland(5, 1);
+        let wall = map.tile_xy_idx(3, 0);
+        map.features[wall] = Some(Features::StoneWall);
+        let costs = travel_costs(&map, [TilePos::new(0, 0)], 100);
+
+        assert_eq!(costs, vec![Some(0), Some(100), None, None, None]);
+    }
+}
@@ -68,4 +68,21 @@ impl Biomes {
     pub fn is_water_source(&self) -> bool {
         matches!(self, Biomes::Ocean)
     }
+
+    /// How fast crops grow on this biome compared to grassland, 0 if nothing can be farmed.
+    pub fn fertility(&self) -> f32 {
+        match self {
+            Biomes::Ocean | Biomes::Empty => 0.0,
+            Biomes::Scorched | Biomes::Stone | Biomes::Snow => 0.1,
+            Biomes::Beach | Biomes::TemperateDesert | Biomes::SubtropicalDesert => 0.3,
+            Biomes::Tundra => 0.4,
+            Biomes::Taiga => 0.6,
+            Biomes::Shrubland => 0.8,
+            Biomes::Grassland => 1.0,
+            Biomes::TemperateDeciduousForest
+            | Biomes::TemperateRainForest
+            | Biomes::TropicalSeasonalForest
+            | Biomes::TropicalRainForest => 0.9,
+        }
+    }
 }
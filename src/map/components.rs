@@ -5,6 +5,42 @@ use crate::SIMULATION_SPEED;
 #[derive(Component, Clone, Copy, Debug)]
 pub struct WaterSource;
 
+/// Limits how many villagers can drink from a water source at the same time.
+#[derive(Component, Clone, Debug)]
+pub struct WaterSourceCapacity {
+    pub max_drinkers: usize,
+    drinkers: Vec<Entity>,
+}
+
+impl WaterSourceCapacity {
+    pub fn new(max_drinkers: usize) -> Self {
+        Self {
+            max_drinkers,
+            drinkers: Vec::new(),
+        }
+    }
+
+    pub fn has_room_for(&self, drinker: Entity) -> bool {
+        self.drinkers.contains(&drinker) || self.drinkers.len() < self.max_drinkers
+    }
+
+    /// Takes one of the spots at the water source, returning whether the drinker has a spot.
+    pub fn claim(&mut self, drinker: Entity) -> bool {
+        if !self.has_room_for(drinker) {
+            return false;
+        }
+
+        if !self.drinkers.contains(&drinker) {
+            self.drinkers.push(drinker);
+        }
+        true
+    }
+
+    pub fn release(&mut self, drinker: Entity) {
+        self.drinkers.retain(|d| *d != drinker);
+    }
+}
+
 #[derive(Component, Clone, Copy, Debug)]
 pub struct Obstacle;
 
@@ -20,8 +56,13 @@ pub struct Growing {
 
 impl Growing {
     pub fn new() -> Self {
+        Self::with_multiplier(1.0)
+    }
+
+    /// Grows `multiplier` times as fast as a regrowing tree.
+    pub fn with_multiplier(multiplier: f32) -> Self {
         Self {
-            speed: 0.001 * SIMULATION_SPEED,
+            speed: 0.001 * SIMULATION_SPEED * multiplier,
             progress: 0.0,
         }
     }
@@ -29,3 +70,14 @@ impl Growing {
 
 #[derive(Component, Clone, Copy, Debug)]
 pub struct Mineable;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Harvestable;
+
+/// A bed that can be given to a single villager as their home.
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct Bed {
+    pub owner: Option<Entity>,
+    //Whether the bed stands in a room small enough to be a home, worked out again when the map changes
+    pub in_room: bool,
+}
@@ -288,6 +288,21 @@ fn fill_feature(
         feature_builder.insert(super::components::Mineable);
     }
 
+    if feature.is_harvestable() {
+        feature_builder.insert(super::components::Harvestable);
+    }
+
+    if *feature == Features::Bed {
+        feature_builder.insert(super::components::Bed::default());
+    }
+
+    if feature.is_water_source() {
+        feature_builder.insert(super::components::WaterSource);
+        if let Some(max_drinkers) = feature.max_drinkers() {
+            feature_builder.insert(super::components::WaterSourceCapacity::new(max_drinkers));
+        }
+    }
+
     feature_builder
         .insert((
             TileBundle {
@@ -1,6 +1,10 @@
+use serde::Deserialize;
+
+use crate::items::{ItemStack, Items};
+
 use super::auto_tile::AutoTileCategory;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
 pub enum Features {
     TreeStump,
     Tree,
@@ -17,6 +21,12 @@ pub enum Features {
     Floor,
     Door,
     Road,
+    Farmland,
+    CropSeedling,
+    CropGrowing,
+    CropRipe,
+    Well,
+    Bed,
 }
 
 impl Features {
@@ -37,6 +47,12 @@ impl Features {
             Features::Floor => "Floor",
             Features::Door => "Door",
             Features::Road => "Road",
+            Features::Farmland => "Farmland",
+            Features::CropSeedling => "CropSeedling",
+            Features::CropGrowing => "CropGrowing",
+            Features::CropRipe => "CropRipe",
+            Features::Well => "Well",
+            Features::Bed => "Bed",
         }
     }
 
@@ -49,7 +65,7 @@ impl Features {
 
     pub fn cost(&self) -> Option<isize> {
         match self {
-            Features::StoneWall | Features::Wall => Some(-1),
+            Features::StoneWall | Features::Wall | Features::Well => Some(-1),
             Features::Road => Some(70),
             _ => None,
         }
@@ -66,4 +82,139 @@ impl Features {
     pub fn is_mineable(&self) -> bool {
         matches!(self, Features::StoneWall | Features::Rocks)
     }
+
+    pub fn is_harvestable(&self) -> bool {
+        matches!(self, Features::AppleTree | Features::BerryBush | Features::CropRipe)
+    }
+
+    /// Whether this is one of the growth stages of a farmed crop.
+    pub fn is_crop(&self) -> bool {
+        matches!(
+            self,
+            Features::CropSeedling | Features::CropGrowing | Features::CropRipe
+        )
+    }
+
+    pub fn harvested(&self) -> Option<Features> {
+        match self {
+            Features::AppleTree => Some(Features::AppleTreeEmpty),
+            Features::BerryBush => Some(Features::BerryBushEmpty),
+            Features::CropRipe => Some(Features::Farmland),
+            _ => None,
+        }
+    }
+
+    pub fn grown(&self) -> Option<Features> {
+        match self {
+            Features::TreeStump => Some(Features::Tree),
+            Features::CoconutTreeStump => Some(Features::CoconutTree),
+            Features::AppleTreeEmpty => Some(Features::AppleTree),
+            Features::BerryBushEmpty => Some(Features::BerryBush),
+            Features::CropSeedling => Some(Features::CropGrowing),
+            Features::CropGrowing => Some(Features::CropRipe),
+            _ => None,
+        }
+    }
+
+    pub fn is_player_built(&self) -> bool {
+        matches!(
+            self,
+            Features::Wall | Features::Floor | Features::Door | Features::Road | Features::Well | Features::Bed
+        )
+    }
+
+    /// How pleasant it is to be next to this feature, between -1 and 1.
+    pub fn comfort(&self) -> f32 {
+        match self {
+            Features::Bed => 1.0,
+            Features::Floor => 0.6,
+            Features::Well | Features::AppleTree | Features::BerryBush => 0.3,
+            Features::Road | Features::Door | Features::Wall => 0.2,
+            Features::TreeStump | Features::CoconutTreeStump | Features::Rocks => -0.3,
+            _ => 0.0,
+        }
+    }
+
+    /// Whether this feature closes off a room, keeping what's inside separate from the outside.
+    pub fn encloses_room(&self) -> bool {
+        matches!(self, Features::StoneWall | Features::Wall | Features::Door)
+    }
+
+    /// The materials a builder has to bring to the site to build this feature, all carried in a single trip.
+    pub fn recipe(&self) -> Option<ItemStack> {
+        match self {
+            Features::Wall | Features::Door => Some(ItemStack::new(Items::Log, 2)),
+            Features::Floor => Some(ItemStack::new(Items::Log, 1)),
+            Features::Road => Some(ItemStack::new(Items::Stone, 1)),
+            Features::Well => Some(ItemStack::new(Items::Stone, 3)),
+            Features::Bed => Some(ItemStack::new(Items::Log, 3)),
+            _ => None,
+        }
+    }
+
+    pub fn is_water_source(&self) -> bool {
+        matches!(self, Features::Well)
+    }
+
+    /// How many villagers can drink from this feature at once, if it is a water source.
+    pub fn max_drinkers(&self) -> Option<usize> {
+        match self {
+            Features::Well => Some(2),
+            _ => None,
+        }
+    }
+
+    /// Materials given back when a player-built feature is deconstructed, half of its recipe rounded up.
+    pub fn deconstruct_refund(&self) -> Option<ItemStack> {
+        self.recipe()
+            .map(|recipe| ItemStack::new(recipe.item, (recipe.count + 1) / 2))
+    }
+
+    pub fn harvest_yield(&self) -> Option<ItemStack> {
+        match self {
+            Features::AppleTree => Some(ItemStack::new(Items::Apple, 5)),
+            Features::BerryBush => Some(ItemStack::new(Items::Berry, 3)),
+            Features::CropRipe => Some(ItemStack::new(Items::Wheat, 4)),
+            _ => None,
+        }
+    }
+
+    /// Items left behind when this feature is chopped, mined or cleared away.
+    pub fn drops(&self) -> Option<ItemStack> {
+        match self {
+            Features::Tree | Features::CoconutTree => Some(ItemStack::new(Items::Log, 3)),
+            Features::AppleTree | Features::AppleTreeEmpty => Some(ItemStack::new(Items::Log, 2)),
+            Features::TreeStump | Features::CoconutTreeStump => Some(ItemStack::new(Items::Log, 1)),
+            Features::Rocks => Some(ItemStack::new(Items::Stone, 2)),
+            Features::StoneWall => Some(ItemStack::new(Items::Stone, 4)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn crops_grow_until_ripe() {
+        assert_eq!(Features::CropSeedling.grown(), Some(Features::CropGrowing));
+        assert_eq!(Features::CropGrowing.grown(), Some(Features::CropRipe));
+        assert_eq!(Features::CropRipe.grown(), None);
+        assert!(!Features::CropSeedling.is_harvestable());
+        assert!(!Features::CropGrowing.is_harvestable());
+        assert!(Features::CropRipe.is_harvestable());
+    }
+
+    #[test]
+    fn harvested_crops_leave_farmland() {
+        assert_eq!(Features::CropRipe.harvested(), Some(Features::Farmland));
+        assert_eq!(
+            Features::CropRipe.harvest_yield(),
+            Some(ItemStack::new(Items::Wheat, 4))
+        );
+        assert_eq!(Features::CropGrowing.harvested(), None);
+        assert!(!Features::Farmland.is_crop());
+        assert_eq!(Features::Farmland.grown(), None);
+    }
 }
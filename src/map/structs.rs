@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use bevy::prelude::{Resource, Vec2};
 use bevy_ecs_tilemap::tiles::TilePos;
 use hierarchical_pathfinding::{internals::AbstractPath, PathCache, PathCacheConfig};
@@ -118,6 +120,32 @@ impl Map {
         }
     }
 
+    /// Whether a tile is part of a room of at most `max_tiles` tiles, closed off by walls and doors.
+    pub fn is_enclosed(&self, tile_pos: &TilePos, max_tiles: usize) -> bool {
+        let mut visited = HashSet::from([*tile_pos]);
+        let mut open = vec![*tile_pos];
+        while let Some(tile) = open.pop() {
+            for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+                let (x, y) = (tile.x as i64 + dx, tile.y as i64 + dy);
+                if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+                    //Reached the edge of the map, so we're outside.
+                    return false;
+                }
+
+                let neighbor = TilePos::new(x as u32, y as u32);
+                let encloses = self.features[self.tile_xy_idx(neighbor.x, neighbor.y)]
+                    .map_or(false, |feature| feature.encloses_room());
+                if !encloses && visited.insert(neighbor) {
+                    if visited.len() > max_tiles {
+                        return false;
+                    }
+                    open.push(neighbor);
+                }
+            }
+        }
+        true
+    }
+
     pub fn is_passable(&self, x: u32, y: u32) -> bool {
         if x >= self.width || y >= self.height {
             return false;
@@ -187,6 +215,29 @@ mod test {
         assert_eq!(coords.y, 3208.0);
     }
 
+    #[test]
+    fn is_enclosed_by_walls() {
+        let mut map = Map::new(10, 10);
+        for x in 2..=6 {
+            for y in 2..=6 {
+                if x == 2 || x == 6 || y == 2 || y == 6 {
+                    let idx = map.tile_xy_idx(x, y);
+                    map.features[idx] = Some(Features::Wall);
+                }
+            }
+        }
+        let door = map.tile_xy_idx(2, 4);
+        map.features[door] = Some(Features::Door);
+
+        assert!(map.is_enclosed(&TilePos::new(4, 4), 9));
+        assert!(!map.is_enclosed(&TilePos::new(4, 4), 8));
+        assert!(!map.is_enclosed(&TilePos::new(8, 8), 100));
+
+        let gap = map.tile_xy_idx(6, 4);
+        map.features[gap] = None;
+        assert!(!map.is_enclosed(&TilePos::new(4, 4), 100));
+    }
+
     #[test]
     fn is_neighbor_test() {
         assert!(is_neighbor(&TilePos::new(2, 2), &TilePos::new(2, 2)));
@@ -0,0 +1,173 @@
+use bevy::prelude::*;
+use bevy_mouse_tracking_plugin::MainCamera;
+
+use crate::SIMULATION_SPEED;
+
+// In-game hours passing every second, a full day takes 300 seconds at normal speed.
+const HOURS_PER_SECOND: f32 = 0.016 * SIMULATION_SPEED;
+// Hour of the first morning.
+const STARTING_HOUR: f32 = 7.0;
+// Distance from the camera to the tint, keeping it in front of the whole map.
+const TINT_DEPTH: f32 = 500.0;
+// Tint colors through the day, as (hour, color) pairs the tint moves between.
+const TINT_KEYFRAMES: [(f32, Color); 8] = [
+    (0.0, Color::rgba(0.05, 0.05, 0.25, 0.55)),
+    (5.0, Color::rgba(0.05, 0.05, 0.25, 0.55)),
+    (6.0, Color::rgba(0.9, 0.5, 0.3, 0.25)),
+    (7.5, Color::rgba(1.0, 1.0, 1.0, 0.0)),
+    (17.5, Color::rgba(1.0, 1.0, 1.0, 0.0)),
+    (19.0, Color::rgba(0.8, 0.35, 0.2, 0.3)),
+    (20.5, Color::rgba(0.05, 0.05, 0.25, 0.55)),
+    (24.0, Color::rgba(0.05, 0.05, 0.25, 0.55)),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TimeOfDay {
+    Dawn,
+    Day,
+    Dusk,
+    Night,
+}
+
+impl TimeOfDay {
+    pub fn from_hour(hour: f32) -> Self {
+        match hour {
+            hour if (5.0..7.5).contains(&hour) => TimeOfDay::Dawn,
+            hour if (7.5..17.5).contains(&hour) => TimeOfDay::Day,
+            hour if (17.5..20.5).contains(&hour) => TimeOfDay::Dusk,
+            _ => TimeOfDay::Night,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TimeOfDay::Dawn => "Dawn",
+            TimeOfDay::Day => "Day",
+            TimeOfDay::Dusk => "Dusk",
+            TimeOfDay::Night => "Night",
+        }
+    }
+}
+
+/// The in-game time, shared by everything following the daily rhythm of the town.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct WorldClock {
+    //Days since the town was founded, starting at 1
+    day: u32,
+    //Hour of the current day, from 0 up to 24
+    hour: f32,
+}
+
+impl Default for WorldClock {
+    fn default() -> Self {
+        Self {
+            day: 1,
+            hour: STARTING_HOUR,
+        }
+    }
+}
+
+impl WorldClock {
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    pub fn hour(&self) -> f32 {
+        self.hour
+    }
+
+    pub fn time_of_day(&self) -> TimeOfDay {
+        TimeOfDay::from_hour(self.hour)
+    }
+
+    pub fn advance(&mut self, hours: f32) {
+        self.hour += hours;
+        while self.hour >= 24.0 {
+            self.hour -= 24.0;
+            self.day += 1;
+        }
+    }
+}
+
+/// The color laid over the map at a given hour.
+fn tint(hour: f32) -> Color {
+    let next = TINT_KEYFRAMES
+        .iter()
+        .position(|(keyframe_hour, _)| *keyframe_hour > hour)
+        .unwrap_or(TINT_KEYFRAMES.len() - 1)
+        .max(1);
+    let (from_hour, from) = TINT_KEYFRAMES[next - 1];
+    let (to_hour, to) = TINT_KEYFRAMES[next];
+    let t = ((hour - from_hour) / (to_hour - from_hour)).clamp(0.0, 1.0);
+    Color::rgba(
+        from.r() + (to.r() - from.r()) * t,
+        from.g() + (to.g() - from.g()) * t,
+        from.b() + (to.b() - from.b()) * t,
+        from.a() + (to.a() - from.a()) * t,
+    )
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct DayNightTint;
+
+pub fn advance_clock(time: Res<Time>, mut clock: ResMut<WorldClock>) {
+    clock.advance(HOURS_PER_SECOND * time.delta_seconds());
+}
+
+pub fn setup_day_night_tint(
+    mut commands: Commands,
+    cameras: Query<Entity, With<MainCamera>>,
+    tints: Query<Entity, With<DayNightTint>>,
+) {
+    if !tints.is_empty() {
+        return;
+    }
+
+    for camera in cameras.iter() {
+        let tint = commands
+            .spawn((
+                SpriteBundle {
+                    sprite: Sprite {
+                        color: Color::NONE,
+                        //Large enough to cover the view however far the camera is zoomed out.
+                        custom_size: Some(Vec2::splat(100_000.0)),
+                        ..default()
+                    },
+                    transform: Transform::from_xyz(0.0, 0.0, -TINT_DEPTH),
+                    ..default()
+                },
+                DayNightTint,
+                Name::new("DayNightTint"),
+            ))
+            .id();
+        commands.entity(camera).add_child(tint);
+    }
+}
+
+pub fn update_day_night_tint(clock: Res<WorldClock>, mut tints: Query<&mut Sprite, With<DayNightTint>>) {
+    for mut sprite in tints.iter_mut() {
+        sprite.color = tint(clock.hour());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn clock_rolls_over_to_next_day() {
+        let mut clock = WorldClock::default();
+        clock.advance(24.0 - STARTING_HOUR + 1.5);
+        assert_eq!(clock.day(), 2);
+        assert!((clock.hour() - 1.5).abs() < 0.001);
+        assert_eq!(clock.time_of_day(), TimeOfDay::Night);
+    }
+
+    #[test]
+    fn tint_is_clear_during_the_day() {
+        assert_eq!(tint(12.0).a(), 0.0);
+        assert!(tint(2.0).a() > 0.5);
+        assert!(tint(6.0).a() > 0.0 && tint(6.0).a() < tint(2.0).a());
+        assert!(tint(23.9).a() > 0.5);
+    }
+}
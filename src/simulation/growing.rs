@@ -1,14 +1,18 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
-use crate::map::{
-    components::{Choppable, Growing},
-    FeatureQuery, Features, Map,
+use crate::{
+    jobs::{job_queue::JobQueue, Jobs},
+    map::{
+        components::{Choppable, Growing, Harvestable},
+        FeatureQuery, Map,
+    },
 };
 
 pub fn grow(
     mut commands: Commands,
     mut map: ResMut<Map>,
+    mut job_queue: ResMut<JobQueue>,
     mut growing_query: Query<(Entity, &TilePos, &mut Growing)>,
     mut feature_query: FeatureQuery,
 ) {
@@ -18,10 +22,13 @@ pub fn grow(
         if growing.progress >= 100.0 {
             let idx = map.tile_xy_idx(tile_pos.x, tile_pos.y);
             let current_feature = map.features[idx].expect("There should be a feature at growing location.");
-            let next_feature = match current_feature {
-                Features::TreeStump => Features::Tree,
-                Features::CoconutTreeStump => Features::CoconutTree,
-                _ => unreachable!(),
+            commands.entity(entity).remove::<Growing>();
+
+            let next_feature = if let Some(next_feature) = current_feature.grown() {
+                next_feature
+            } else {
+                warn!("{:?} at {:?} has nothing to grow into.", current_feature, tile_pos);
+                continue;
             };
 
             map.features[idx] = Some(next_feature);
@@ -31,7 +38,21 @@ pub fn grow(
                 commands.entity(entity).insert(Choppable);
             }
 
-            commands.entity(entity).remove::<Growing>();
+            if next_feature.is_harvestable() {
+                commands.entity(entity).insert(Harvestable);
+                if next_feature.is_crop() {
+                    //Nobody has to order a ripe crop to be harvested.
+                    job_queue.push(Jobs::Harvest, *tile_pos);
+                }
+            }
+
+            if next_feature.grown().is_some() {
+                //Crops go through several stages, each growing as fast as the last.
+                commands.entity(entity).insert(Growing {
+                    progress: 0.0,
+                    ..*growing
+                });
+            }
         }
     }
 }
@@ -3,17 +3,24 @@ use iyes_loopless::prelude::*;
 
 use crate::states::GameStates;
 
+mod clock;
 mod growing;
 
+pub use clock::WorldClock;
+
 pub struct SimulationPlugin;
 
 impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
-        app.add_system_set(
-            ConditionSet::new()
-                .run_in_state(GameStates::InGame)
-                .with_system(growing::grow)
-                .into(),
-        );
+        app.init_resource::<WorldClock>()
+            .add_enter_system(GameStates::InGame, clock::setup_day_night_tint)
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameStates::InGame)
+                    .with_system(growing::grow)
+                    .with_system(clock::advance_clock)
+                    .with_system(clock::update_day_night_tint)
+                    .into(),
+            );
     }
 }
@@ -0,0 +1,28 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, LoadedAsset},
+    utils::BoxedFuture,
+};
+
+use super::TechTree;
+
+#[derive(Default)]
+pub struct TechTreeLoader;
+
+impl AssetLoader for TechTreeLoader {
+    fn load<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        load_context: &'a mut LoadContext,
+    ) -> BoxedFuture<'a, Result<(), bevy::asset::Error>> {
+        Box::pin(async move {
+            let tree = ron::de::from_bytes::<TechTree>(bytes)?;
+            tree.validate().map_err(bevy::asset::Error::msg)?;
+            load_context.set_default_asset(LoadedAsset::new(tree));
+            Ok(())
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tech.ron"]
+    }
+}
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use bevy_asset_loader::prelude::*;
+use iyes_loopless::prelude::*;
+
+use crate::states::GameStates;
+
+mod loader;
+mod registry;
+mod research;
+
+pub use registry::*;
+pub use research::*;
+
+#[derive(Resource, AssetCollection)]
+pub struct TechAssets {
+    #[asset(path = "techs/tree.tech.ron")]
+    tree: Handle<TechTree>,
+}
+
+pub struct TechPlugin;
+
+impl Plugin for TechPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_asset::<TechTree>()
+            .init_asset_loader::<loader::TechTreeLoader>()
+            .add_event::<TechResearched>()
+            .add_system_set(
+                ConditionSet::new()
+                    .run_in_state(GameStates::InGame)
+                    .with_system(advance_research)
+                    .with_system(apply_speed_upgrades)
+                    .into(),
+            );
+    }
+}
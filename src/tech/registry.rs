@@ -0,0 +1,77 @@
+use std::collections::HashSet;
+
+use bevy::reflect::TypeUuid;
+use serde::Deserialize;
+
+use crate::map::Features;
+
+/// Names a tech in the tech tree asset.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct TechId(pub String);
+
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+pub enum TechEffect {
+    //Multiplies the travel speed of every villager
+    SpeedMultiplier(f32),
+    //Allows the feature to be built
+    Unlock(Features),
+    //Lowers the number of materials needed to build the feature
+    CheaperRecipe(Features, u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+pub struct TechDefinition {
+    pub id: TechId,
+    pub name: String,
+    //Research points needed to finish the tech
+    pub cost: f32,
+    //Techs that must be researched before this one can be started
+    pub prerequisites: Vec<TechId>,
+    pub effects: Vec<TechEffect>,
+}
+
+/// Every tech the town can research, described in a `.tech.ron` asset.
+#[derive(Clone, Debug, Deserialize, TypeUuid)]
+#[uuid = "9e2b7c41-3f6d-4a85-b1e0-7d4c2a8f5e16"]
+pub struct TechTree {
+    //In the order they're shown in the research menu
+    techs: Vec<TechDefinition>,
+}
+
+impl TechTree {
+    pub fn techs(&self) -> impl Iterator<Item = &TechDefinition> {
+        self.techs.iter()
+    }
+
+    pub fn definition(&self, tech: &TechId) -> Option<&TechDefinition> {
+        self.techs.iter().find(|definition| definition.id == *tech)
+    }
+
+    /// The tech that has to be researched before the feature can be built, if any.
+    pub fn unlocking(&self, feature: Features) -> Option<&TechDefinition> {
+        self.techs
+            .iter()
+            .find(|definition| definition.effects.contains(&TechEffect::Unlock(feature)))
+    }
+
+    /// Checks that every tech is defined once and only needs techs defined in the tree.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut ids = HashSet::new();
+        for definition in self.techs.iter() {
+            if !ids.insert(&definition.id) {
+                return Err(format!("{} is defined more than once.", definition.id.0));
+            }
+        }
+        for definition in self.techs.iter() {
+            if let Some(unknown) = definition
+                .prerequisites
+                .iter()
+                .find(|prerequisite| !ids.contains(prerequisite))
+            {
+                return Err(format!("{} needs the unknown tech {}.", definition.id.0, unknown.0));
+            }
+        }
+        Ok(())
+    }
+}
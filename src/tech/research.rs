@@ -0,0 +1,243 @@
+use std::collections::HashSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    ai::characteristics::{JobSeeker, Speed},
+    items::ItemStack,
+    map::Features,
+    SIMULATION_SPEED,
+};
+
+use super::{TechAssets, TechDefinition, TechEffect, TechId, TechTree};
+
+// Research points gained every second while a tech is being researched.
+const RESEARCH_PER_SECOND: f32 = 1.0 * SIMULATION_SPEED;
+
+/// Sent once a tech has been fully researched.
+#[derive(Clone, Debug)]
+pub struct TechResearched(pub TechId);
+
+/// Which techs the town knows and which one it is working on.
+#[derive(Resource, Clone, Debug)]
+pub struct Research {
+    tree: TechTree,
+    researched: HashSet<TechId>,
+    current: Option<TechId>,
+    progress: f32,
+}
+
+impl FromWorld for Research {
+    fn from_world(world: &mut World) -> Self {
+        let tech_assets = world.resource::<TechAssets>();
+        let trees = world.resource::<Assets<TechTree>>();
+        let tree = trees
+            .get(&tech_assets.tree)
+            .expect("Tech tree should be loaded before research starts.");
+        Research::new(tree.clone())
+    }
+}
+
+impl Research {
+    pub fn new(tree: TechTree) -> Self {
+        Self {
+            tree,
+            researched: HashSet::new(),
+            current: None,
+            progress: 0.0,
+        }
+    }
+
+    /// Every tech in the tree, in the order they're shown to the player.
+    pub fn techs(&self) -> impl Iterator<Item = &TechDefinition> {
+        self.tree.techs()
+    }
+
+    pub fn definition(&self, tech: &TechId) -> Option<&TechDefinition> {
+        self.tree.definition(tech)
+    }
+
+    /// The tech that has to be researched before the feature can be built, if any.
+    pub fn unlocking(&self, feature: Features) -> Option<&TechDefinition> {
+        self.tree.unlocking(feature)
+    }
+
+    pub fn is_researched(&self, tech: &TechId) -> bool {
+        self.researched.contains(tech)
+    }
+
+    /// Whether the tech isn't known yet but all of its prerequisites are.
+    pub fn can_research(&self, tech: &TechId) -> bool {
+        !self.is_researched(tech)
+            && self.definition(tech).map_or(false, |definition| {
+                definition
+                    .prerequisites
+                    .iter()
+                    .all(|prerequisite| self.is_researched(prerequisite))
+            })
+    }
+
+    pub fn current(&self) -> Option<&TechDefinition> {
+        self.current.as_ref().and_then(|tech| self.definition(tech))
+    }
+
+    /// Fraction of the current tech that has been researched.
+    pub fn progress(&self) -> f32 {
+        self.current().map_or(0.0, |definition| self.progress / definition.cost)
+    }
+
+    /// Switches research to `tech`, dropping the progress made on the previous one.
+    pub fn select(&mut self, tech: &TechId) -> bool {
+        if !self.can_research(tech) {
+            return false;
+        }
+        if self.current.as_ref() != Some(tech) {
+            self.current = Some(tech.clone());
+            self.progress = 0.0;
+        }
+        true
+    }
+
+    /// Adds research points to the current tech, returning it if it got finished.
+    pub fn advance(&mut self, points: f32) -> Option<TechId> {
+        let cost = self.current()?.cost;
+        self.progress += points;
+        if self.progress < cost {
+            return None;
+        }
+
+        let tech = self.current.take()?;
+        self.researched.insert(tech.clone());
+        self.progress = 0.0;
+        Some(tech)
+    }
+
+    fn effects(&self) -> impl Iterator<Item = &TechEffect> {
+        self.researched
+            .iter()
+            .filter_map(|tech| self.definition(tech))
+            .flat_map(|definition| definition.effects.iter())
+    }
+
+    /// Combined speed bonus of every researched tech.
+    pub fn speed_multiplier(&self) -> f32 {
+        self.effects()
+            .map(|effect| match effect {
+                TechEffect::SpeedMultiplier(multiplier) => *multiplier,
+                _ => 1.0,
+            })
+            .product()
+    }
+
+    pub fn is_buildable(&self, feature: Features) -> bool {
+        self.unlocking(feature)
+            .map_or(true, |definition| self.is_researched(&definition.id))
+    }
+
+    /// The materials needed to build a feature, after every researched discount.
+    pub fn recipe(&self, feature: Features) -> Option<ItemStack> {
+        let mut recipe = feature.recipe()?;
+        for effect in self.effects() {
+            if let TechEffect::CheaperRecipe(discounted, amount) = effect {
+                if *discounted == feature {
+                    recipe.count = recipe.count.saturating_sub(*amount);
+                }
+            }
+        }
+        //A recipe always needs at least one item.
+        recipe.count = recipe.count.max(1);
+        Some(recipe)
+    }
+}
+
+pub fn advance_research(time: Res<Time>, mut research: ResMut<Research>, mut researched: EventWriter<TechResearched>) {
+    if research.current().is_none() {
+        return;
+    }
+
+    if let Some(tech) = research.advance(RESEARCH_PER_SECOND * time.delta_seconds()) {
+        if let Some(definition) = research.definition(&tech) {
+            info!("Finished researching {}.", definition.name);
+        }
+        researched.send(TechResearched(tech));
+    }
+}
+
+/// Speeds up the villagers already in town, newcomers get every bonus when they're spawned.
+pub fn apply_speed_upgrades(
+    research: Res<Research>,
+    mut researched: EventReader<TechResearched>,
+    mut speeds: Query<&mut Speed, With<JobSeeker>>,
+) {
+    for TechResearched(tech) in researched.iter() {
+        let effects = research
+            .definition(tech)
+            .map_or(&[][..], |definition| &definition.effects);
+        for effect in effects.iter() {
+            if let TechEffect::SpeedMultiplier(multiplier) = effect {
+                for mut speed in speeds.iter_mut() {
+                    speed.speed *= multiplier;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::items::Items;
+
+    fn shipped_research() -> Research {
+        let tree = ron::de::from_str(include_str!("../../assets/techs/tree.tech.ron"))
+            .expect("Shipped tech tree should be valid.");
+        Research::new(tree)
+    }
+
+    fn id(name: &str) -> TechId {
+        TechId(name.to_string())
+    }
+
+    fn finish(research: &mut Research, tech: &str) {
+        assert!(research.select(&id(tech)));
+        let cost = research.current().expect("Tech should be selected.").cost;
+        assert_eq!(research.advance(cost), Some(id(tech)));
+    }
+
+    #[test]
+    fn shipped_tree_is_valid() {
+        let research = shipped_research();
+        assert_eq!(research.tree.validate(), Ok(()));
+        assert!(research.techs().all(|definition| !definition.name.is_empty()));
+    }
+
+    #[test]
+    fn unknown_prerequisites_are_rejected() {
+        let tree: TechTree = ron::de::from_str(
+            r#"(techs: [(id: "Masonry", name: "Masonry", cost: 1.0, prerequisites: ["Mining"], effects: [])])"#,
+        )
+        .expect("Tree should deserialize.");
+        assert!(tree.validate().is_err());
+        assert!(!Research::new(tree).can_research(&id("Masonry")));
+    }
+
+    #[test]
+    fn prerequisites_gate_research() {
+        let mut research = shipped_research();
+        assert!(!research.select(&id("Masonry")));
+
+        finish(&mut research, "WellDigging");
+        assert!(research.is_buildable(Features::Well));
+        assert!(research.select(&id("Masonry")));
+    }
+
+    #[test]
+    fn discounts_lower_recipes() {
+        let mut research = shipped_research();
+        assert_eq!(research.recipe(Features::Bed), Some(ItemStack::new(Items::Log, 3)));
+
+        finish(&mut research, "Carpentry");
+        assert_eq!(research.recipe(Features::Bed), Some(ItemStack::new(Items::Log, 2)));
+        assert_eq!(research.recipe(Features::Floor), Some(ItemStack::new(Items::Log, 1)));
+    }
+}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+use super::Zone;
+
+const FARM_COLOR: Color = Color::rgba(0.3, 0.8, 0.2, 0.2);
+
+/// A zone where villagers till the soil, then keep sowing and harvesting crops.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Farm;
+
+pub fn spawn_farm(commands: &mut Commands, zone: Zone) -> Entity {
+    commands
+        .spawn((zone.overlay(FARM_COLOR), zone, Farm, Name::from("Farm")))
+        .id()
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::map::{tile_xy_world_xy, TILE_SIZE};
+
+mod farm;
+mod stockpile;
+
+pub use farm::*;
+pub use stockpile::*;
+
+/// A rectangle of tiles designated by the player for a purpose.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Zone {
+    pub min: TilePos,
+    pub max: TilePos,
+}
+
+impl Zone {
+    pub fn new(corner: TilePos, opposite_corner: TilePos) -> Self {
+        Self {
+            min: TilePos::new(
+                u32::min(corner.x, opposite_corner.x),
+                u32::min(corner.y, opposite_corner.y),
+            ),
+            max: TilePos::new(
+                u32::max(corner.x, opposite_corner.x),
+                u32::max(corner.y, opposite_corner.y),
+            ),
+        }
+    }
+
+    pub fn area(&self) -> u32 {
+        (self.max.x - self.min.x + 1) * (self.max.y - self.min.y + 1)
+    }
+
+    #[allow(dead_code)]
+    pub fn contains(&self, tile_pos: &TilePos) -> bool {
+        (self.min.x..=self.max.x).contains(&tile_pos.x) && (self.min.y..=self.max.y).contains(&tile_pos.y)
+    }
+
+    /// Whether the tile is inside the zone or right next to it.
+    pub fn is_neighbor(&self, tile_pos: &TilePos) -> bool {
+        crate::map::is_neighbor(&self.closest_tile(tile_pos), tile_pos)
+    }
+
+    pub fn closest_tile(&self, tile_pos: &TilePos) -> TilePos {
+        TilePos::new(
+            tile_pos.x.clamp(self.min.x, self.max.x),
+            tile_pos.y.clamp(self.min.y, self.max.y),
+        )
+    }
+
+    pub fn tiles(&self) -> impl Iterator<Item = TilePos> + '_ {
+        (self.min.y..=self.max.y).flat_map(move |y| (self.min.x..=self.max.x).map(move |x| TilePos::new(x, y)))
+    }
+
+    /// A tinted sprite drawn over the whole zone.
+    pub fn overlay(&self, color: Color) -> SpriteBundle {
+        let min = tile_xy_world_xy(self.min.x, self.min.y);
+        let max = tile_xy_world_xy(self.max.x, self.max.y);
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(max - min + Vec2::new(TILE_SIZE.x, TILE_SIZE.y)),
+                ..default()
+            },
+            transform: Transform::from_translation(((min + max) / 2.0).extend(1.2)),
+            ..default()
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+
+use crate::items::{Container, ItemCategory, Items, Storage};
+
+use super::Zone;
+
+const STOCKPILE_COLOR: Color = Color::rgba(0.9, 0.8, 0.2, 0.25);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItemFilter {
+    All,
+    Only(ItemCategory),
+}
+
+impl ItemFilter {
+    pub fn accepts(&self, item: Items) -> bool {
+        match self {
+            ItemFilter::All => true,
+            ItemFilter::Only(category) => item.category() == *category,
+        }
+    }
+}
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Stockpile {
+    pub filter: ItemFilter,
+}
+
+/// Designates a stockpile over a zone, holding one stack of items per tile.
+pub fn spawn_stockpile(commands: &mut Commands, zone: Zone, filter: ItemFilter) -> Entity {
+    commands
+        .spawn((
+            zone.overlay(STOCKPILE_COLOR),
+            zone,
+            Stockpile { filter },
+            Storage,
+            Container::with_slots(zone.area() as usize),
+            Name::from("Stockpile"),
+        ))
+        .id()
+}